@@ -38,7 +38,10 @@ impl Profile {
         self.bio.clone().unwrap_or_default()
     }
 
-    pub async fn try_new(user_id: i32, pool: &sqlx::PgPool) -> Result<Self> {
+    pub async fn try_new<'e, E>(user_id: i32, executor: E) -> Result<Self>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+    {
         let profile = ProfileDefault {
             user_id,
             id: None,
@@ -46,7 +49,7 @@ impl Profile {
             display_name: None,
         };
         profile
-            .create(pool)
+            .create(executor)
             .await
             .map_err(UserInputError::DatabaseError)
     }
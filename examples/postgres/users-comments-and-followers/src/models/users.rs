@@ -119,10 +119,13 @@ impl User {
     pub async fn update_profile(id: Option<i32>, pool: &sqlx::PgPool) -> Result<(User, Profile)> {
         let prompt = "Select the user whose profile you want to update";
         let user = Self::get_user_by_id_or_select(id, prompt, pool).await?;
-        let profile = match user.get_profile(pool).await? {
-            Some(profile) => profile,
-            None => Profile::try_new(user.id, pool).await?,
-        };
+        let profile = georm::transaction(pool, async |conn| {
+            match user.get_profile(&mut *conn).await? {
+                Some(profile) => Ok(profile),
+                None => Profile::try_new(user.id, &mut *conn).await,
+            }
+        })
+        .await?;
         Ok((user, profile))
     }
 }
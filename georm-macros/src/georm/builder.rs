@@ -0,0 +1,175 @@
+//! This module creates a typestate builder for the defaultable version of a
+//! struct derived with Georm. It creates a new struct named
+//! `<StructName>DefaultBuilder`, returned by `<StructName>::builder()`, with a
+//! setter for each field of `<StructName>Default`.
+//!
+//! Each required field, the ones that are not defaultable, has its own generic
+//! parameter, which is `()` until the field is set and `(T,)` afterwards. Its
+//! setter is only available while it is unset, and `build` only once every
+//! required field is set, so that missing fields are caught at compile time.
+//! Defaultable fields can be set at any time, and are left to their database
+//! default otherwise.
+
+use super::ir::GeormField;
+use heck::ToUpperCamelCase;
+use quote::quote;
+use syn::ext::IdentExt;
+
+/// Generic parameter holding the state of a required field.
+fn state_param(field: &GeormField) -> syn::Ident {
+    quote::format_ident!(
+        "__{}",
+        field.ident.unraw().to_string().to_upper_camel_case()
+    )
+}
+
+pub fn derive_builder(ast: &syn::DeriveInput, fields: &[GeormField]) -> proc_macro2::TokenStream {
+    // Only generate along with the defaultable struct
+    if fields.iter().all(|field| !field.defaultable) {
+        return quote! {};
+    }
+
+    let struct_name = &ast.ident;
+    let vis = &ast.vis;
+    let defaultable_struct_name = quote::format_ident!("{}Default", struct_name);
+    let builder_name = quote::format_ident!("{}DefaultBuilder", struct_name);
+
+    // Fields of the defaultable struct
+    let fields: Vec<&GeormField> = fields
+        .iter()
+        .filter(|f| !f.skip && !f.generated && f.sql_value().is_none())
+        .collect();
+    let required: Vec<&GeormField> = fields.iter().copied().filter(|f| !f.defaultable).collect();
+    let params: Vec<syn::Ident> = required.iter().map(|f| state_param(f)).collect();
+
+    let builder_fields = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        match (field.defaultable, field.is_nullable()) {
+            (true, true) => quote! { #ident: ::georm::DefaultOr<#ty> },
+            (true, false) => quote! { #ident: Option<#ty> },
+            (false, _) => {
+                let param = state_param(field);
+                quote! { #ident: #param }
+            }
+        }
+    });
+    let initial_values = fields.iter().map(|field| {
+        let ident = &field.ident;
+        if field.defaultable {
+            quote! { #ident: ::core::default::Default::default() }
+        } else {
+            quote! { #ident: () }
+        }
+    });
+
+    // Setters of required fields change the state of their field only
+    let required_setters = required.iter().enumerate().map(|(i, field)| {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        let field_vis = &field.field.vis;
+        let other_params: Vec<&syn::Ident> = params
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, param)| param)
+            .collect();
+        let state = |set: proc_macro2::TokenStream| {
+            let states = params.iter().enumerate().map(|(j, param)| {
+                if j == i {
+                    set.clone()
+                } else {
+                    quote! { #param }
+                }
+            });
+            quote! { #builder_name<#(#states),*> }
+        };
+        let unset = state(quote! { () });
+        let set = state(quote! { (#ty,) });
+        let moved = fields.iter().map(|f| {
+            let other = &f.ident;
+            if f.ident == field.ident {
+                quote! { #other: (#ident,) }
+            } else {
+                quote! { #other: self.#other }
+            }
+        });
+        quote! {
+            impl<#(#other_params),*> #unset {
+                #field_vis fn #ident(self, #ident: #ty) -> #set {
+                    #builder_name {
+                        #(#moved),*
+                    }
+                }
+            }
+        }
+    });
+
+    let optional_setters = fields.iter().filter(|f| f.defaultable).map(|field| {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        let field_vis = &field.field.vis;
+        let value = if field.is_nullable() {
+            quote! { ::georm::DefaultOr::Value(#ident) }
+        } else {
+            quote! { Some(#ident) }
+        };
+        quote! {
+            #field_vis fn #ident(mut self, #ident: #ty) -> Self {
+                self.#ident = #value;
+                self
+            }
+        }
+    });
+
+    let unset_types = required.iter().map(|_| quote! { () });
+    let required_types = required.iter().map(|field| {
+        let ty = &field.ty;
+        quote! { (#ty,) }
+    });
+    let built_values = fields.iter().map(|field| {
+        let ident = &field.ident;
+        if field.defaultable {
+            quote! { #ident: self.#ident }
+        } else {
+            quote! { #ident: self.#ident.0 }
+        }
+    });
+
+    let builder_doc = format!(
+        "Start building a `{defaultable_struct_name}`, to be created with \
+        `Defaultable::create`. Every field that is not defaultable must be set \
+        before calling `build`."
+    );
+
+    quote! {
+        #vis struct #builder_name<#(#params),*> {
+            #(#builder_fields),*
+        }
+
+        impl #struct_name {
+            #[doc = #builder_doc]
+            #vis fn builder() -> #builder_name<#(#unset_types),*> {
+                #builder_name {
+                    #(#initial_values),*
+                }
+            }
+        }
+
+        #(#required_setters)*
+
+        impl<#(#params),*> #builder_name<#(#params),*> {
+            #(#optional_setters)*
+        }
+
+        impl #builder_name<#(#required_types),*> {
+            /// Build the defaultable struct, leaving the defaultable fields that
+            /// were not set to their database default.
+            #vis fn build(self) -> #defaultable_struct_name {
+                #defaultable_struct_name {
+                    #(#built_values),*
+                }
+            }
+        }
+    }
+}
@@ -5,7 +5,8 @@ use quote::quote;
 pub enum IdType {
     Simple {
         field_name: syn::Ident,
-        field_type: syn::Type,
+        field_type: Box<syn::Type>,
+        column: String,
     },
     Composite {
         fields: Vec<IdField>,
@@ -17,6 +18,18 @@ pub enum IdType {
 pub struct IdField {
     pub name: syn::Ident,
     pub ty: syn::Type,
+    /// Column identifier to use in SQL
+    pub column: String,
+}
+
+impl IdField {
+    /// Argument of a `query!` macro passing the member of `id`, with its type
+    /// overridden like regular fields.
+    pub fn query_arg(&self, id: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let ty = &self.ty;
+        quote! { &#id.#name as &#ty }
+    }
 }
 
 fn field_to_code(field: &GeormField) -> proc_macro2::TokenStream {
@@ -62,6 +75,7 @@ pub fn create_primary_key(
         .map(|field| IdField {
             name: field.ident.clone(),
             ty: field.ty.clone(),
+            column: field.column(),
         })
         .collect();
     match id_fields.len() {
@@ -69,7 +83,8 @@ pub fn create_primary_key(
         1 => (
             IdType::Simple {
                 field_name: id_fields[0].name.clone(),
-                field_type: id_fields[0].ty.clone(),
+                field_type: Box::new(id_fields[0].ty.clone()),
+                column: id_fields[0].column.clone(),
             },
             quote! {},
         ),
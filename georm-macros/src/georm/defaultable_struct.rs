@@ -3,12 +3,26 @@
 //! marked as defaultable become an `Option<T>`, where `T` is the initial type
 //! of the field.
 //!
-//! The user does not have to mark a field defaultable if the field already has
-//! a type `Option<T>`. It is intended only for fields marked as `NOT NULL` in
-//! the database, but not required when creating the entity due to a `DEFAULT`
-//! or something similar. The type `<StructName>Default` implements the
-//! `Defaultable` trait.
+//! It is intended for fields not required when creating the entity due to a
+//! `DEFAULT` or something similar. Fields of type `Option<T>`, stored in nullable
+//! columns, become a `georm::DefaultOr<Option<T>>` instead, so that the default
+//! can be told apart from `NULL`. The type `<StructName>Default` implements the
+//! `Defaultable` trait, whose `create` method matches on the fields provided and
+//! runs the `query!` statement generated for this combination. Above
+//! `MAX_CHECKED_DEFAULTABLE_FIELDS` defaultable fields, the number of
+//! combinations would make compilation too slow, and the statement is built at
+//! runtime from the fields provided instead, only the one writing every field
+//! being checked.
+//!
+//! It can be converted from the entity, and implements `Default` when every
+//! field that is not defaultable does. Other traits can be derived on it by
+//! listing them in the struct-level `default_derive` attribute.
+//!
+//! Fields marked with `#[georm(skip)]` or `#[georm(generated)]` are not part of
+//! `<StructName>Default`, since they are never inserted, and neither are
+//! timestamps, which are set by the database.
 
+use super::IdType;
 use super::ir::{GeormField, GeormStructAttributes};
 use quote::quote;
 
@@ -17,12 +31,13 @@ fn create_defaultable_field(field: &GeormField) -> proc_macro2::TokenStream {
     let ty = &field.ty;
     let vis = &field.field.vis;
 
-    // If the field is marked as defaultable, wrap it in Option<T>
+    // If the field is marked as defaultable, wrap it in Option<T>, or in
+    // DefaultOr<T> if it is already an Option<T>
     // Otherwise, keep the original type
-    let field_type = if field.defaultable {
-        quote! { Option<#ty> }
-    } else {
-        quote! { #ty }
+    let field_type = match (field.defaultable, field.is_nullable()) {
+        (true, true) => quote! { ::georm::DefaultOr<#ty> },
+        (true, false) => quote! { Option<#ty> },
+        (false, _) => quote! { #ty },
     };
 
     quote! {
@@ -30,84 +45,408 @@ fn create_defaultable_field(field: &GeormField) -> proc_macro2::TokenStream {
     }
 }
 
-fn generate_defaultable_trait_impl(
-    struct_name: &syn::Ident,
-    defaultable_struct_name: &syn::Ident,
-    struct_attrs: &GeormStructAttributes,
+/// Maximum number of defaultable fields of a struct for which every combination
+/// of them gets its own checked query, their number growing exponentially.
+const MAX_CHECKED_DEFAULTABLE_FIELDS: usize = 4;
+
+/// Pattern matching a provided defaultable field, binding its value to `value`.
+fn provided_pattern(field: &GeormField, value: &syn::Ident) -> proc_macro2::TokenStream {
+    if field.is_nullable() {
+        quote! { ::georm::DefaultOr::Value(#value) }
+    } else {
+        quote! { Some(#value) }
+    }
+}
+
+/// Pattern matching a defaultable field left to its database default.
+fn default_pattern(field: &GeormField) -> proc_macro2::TokenStream {
+    if field.is_nullable() {
+        quote! { ::georm::DefaultOr::Default }
+    } else {
+        quote! { None }
+    }
+}
+
+/// `ON CONFLICT` clause of upserts of the `inserted` fields on the `target`
+/// columns, only updating the columns provided.
+fn on_conflict_clause(table: &str, target: &str, inserted: &[GeormField]) -> String {
+    let assignments = super::traits::upsert_assignments(table, inserted, target);
+    format!(" ON CONFLICT ({target}) DO UPDATE SET {assignments}")
+}
+
+/// `INSERT` statement of the `inserted` fields, upserting on the
+/// `conflict_target` columns if any, and returning every column of `fields`.
+fn insert_statement(
+    table: &str,
     fields: &[GeormField],
+    inserted: &[GeormField],
+    conflict_target: Option<&str>,
+) -> String {
+    let returning = super::traits::select_columns(fields);
+    let on_conflict = conflict_target
+        .map(|target| on_conflict_clause(table, target, inserted))
+        .unwrap_or_default();
+    if inserted.is_empty() {
+        format!("INSERT INTO {table} DEFAULT VALUES{on_conflict} RETURNING {returning}")
+    } else {
+        format!(
+            "INSERT INTO {table} ({}) VALUES ({}){on_conflict} RETURNING {returning}",
+            inserted
+                .iter()
+                .map(GeormField::column)
+                .collect::<Vec<String>>()
+                .join(", "),
+            super::traits::values(inserted).join(", ")
+        )
+    }
+}
+
+/// Match arm inserting the entity when only the defaultable fields whose bit is
+/// set in `provided` are given a value, upserting it on the `conflict_target`
+/// columns if any.
+fn generate_insert_arm(
+    table: &str,
+    fields: &[GeormField],
+    defaultable_fields: &[&GeormField],
+    provided: usize,
+    conflict_target: Option<&str>,
+    from_record: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    let table = &struct_attrs.table;
+    let is_provided = |field: &GeormField| {
+        defaultable_fields
+            .iter()
+            .position(|f| f.ident == field.ident)
+            .is_none_or(|i| provided & (1 << i) != 0)
+    };
+    let value_ident = |i: usize| quote::format_ident!("value_{}", i);
 
-    // Find the ID field
-    let id_field = fields
+    let patterns = defaultable_fields.iter().enumerate().map(|(i, field)| {
+        if provided & (1 << i) != 0 {
+            provided_pattern(field, &value_ident(i))
+        } else {
+            default_pattern(field)
+        }
+    });
+
+    // Generated columns are never written, and defaultable fields only when
+    // provided
+    let inserted: Vec<GeormField> = fields
+        .iter()
+        .filter(|f| !f.generated && is_provided(f))
+        .cloned()
+        .collect();
+    let args = super::traits::bound_fields(&inserted)
         .iter()
-        .find(|field| field.id)
-        .expect("Must have an ID field");
-    let id_type = &id_field.ty;
+        .map(|field| {
+            let ident = &field.ident;
+            match defaultable_fields
+                .iter()
+                .position(|f| f.ident == field.ident)
+            {
+                Some(i) => {
+                    let value = value_ident(i);
+                    field.query_arg(quote! { (*#value) })
+                }
+                None => field.query_arg(quote! { self.#ident }),
+            }
+        })
+        .collect::<Vec<_>>();
+    let insert_string = insert_statement(table, fields, &inserted, conflict_target);
 
-    // Separate defaultable and non-defaultable fields
-    let non_defaultable_fields: Vec<_> = fields.iter().filter(|f| !f.defaultable).collect();
-    let defaultable_fields: Vec<_> = fields.iter().filter(|f| f.defaultable).collect();
+    quote! {
+        (#(#patterns,)*) => {
+            ::sqlx::query!(#insert_string #(, #args)*)
+                .try_map(#from_record)
+                .fetch_one(executor)
+                .await
+        }
+    }
+}
 
-    // Build static parts for non-defaultable fields
-    let static_field_names: Vec<String> = non_defaultable_fields
+/// Body inserting the entity with a statement built at runtime, only writing the
+/// defaultable fields that are provided, and upserting it on the
+/// `conflict_target` columns if any. Its rows are decoded through `FromRow`.
+///
+/// The statement writing every field, along with the decoding of its rows, is
+/// still checked at compile time by a `query!` that never runs, so that the
+/// table, its columns and their types are verified like the ones of the arms.
+fn generate_runtime_insert(
+    table: &str,
+    struct_name: &syn::Ident,
+    fields: &[GeormField],
+    conflict_target: Option<&str>,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let written: Vec<GeormField> = fields.iter().filter(|f| !f.generated).cloned().collect();
+    let check = insert_statement(table, fields, &written, conflict_target);
+    let check_args = super::traits::bound_fields(&written)
         .iter()
-        .map(|f| f.ident.to_string())
-        .collect();
-    let static_field_idents: Vec<&syn::Ident> =
-        non_defaultable_fields.iter().map(|f| &f.ident).collect();
+        .map(|field| {
+            let ident = &field.ident;
+            field.query_arg(quote! { entity.#ident })
+        })
+        .collect::<Vec<_>>();
 
-    // Generate field checks for defaultable fields
-    let mut field_checks = Vec::new();
+    let value = syn::Ident::new("value", proc_macro2::Span::call_site());
+    let mut column_checks = Vec::new();
     let mut bind_checks = Vec::new();
 
-    for field in &defaultable_fields {
-        let field_name = field.ident.to_string();
-        let field_ident = &field.ident;
-
-        field_checks.push(quote! {
-            if self.#field_ident.is_some() {
-                dynamic_fields.push(#field_name);
+    // Generated columns are never written
+    for field in fields.iter().filter(|f| !f.generated) {
+        let ident = &field.ident;
+        let column = field.column();
+        let placeholder = match field.sql_value() {
+            Some(sql_value) => quote! { String::from(#sql_value) },
+            None => quote! {{
+                bound_count += 1;
+                format!("${}", bound_count)
+            }},
+        };
+        let assignment = match conflict_target {
+            Some(_) if field.version => {
+                let assignment = format!("{column} = {table}.{column} + 1");
+                quote! { assignments.push(#assignment); }
             }
-        });
-
-        bind_checks.push(quote! {
-            if let Some(ref value) = self.#field_ident {
-                query_builder = query_builder.bind(value);
+            Some(_) if field.is_updatable() => {
+                let assignment = format!("{column} = EXCLUDED.{column}");
+                quote! { assignments.push(#assignment); }
+            }
+            _ => quote! {},
+        };
+        let push = quote! {
+            columns.push(#column);
+            values.push(#placeholder);
+            #assignment
+        };
+        let bind = match field.sql_value() {
+            Some(_) => quote! {},
+            None if field.defaultable => {
+                let value = field.bind_value(quote! { (*#value) });
+                quote! { query_builder = query_builder.bind(#value); }
+            }
+            None => {
+                let value = field.bind_value(quote! { self.#ident });
+                quote! { query_builder = query_builder.bind(#value); }
             }
-        });
+        };
+        if field.defaultable {
+            let pattern = provided_pattern(field, &value);
+            column_checks.push(quote! {
+                if let #pattern = &self.#ident {
+                    #push
+                }
+            });
+            bind_checks.push(quote! {
+                if let #pattern = &self.#ident {
+                    #bind
+                }
+            });
+        } else {
+            column_checks.push(push);
+            bind_checks.push(bind);
+        }
     }
 
+    let returning = super::traits::row_columns(fields);
+    let on_conflict = match conflict_target {
+        Some(target) => {
+            // DO UPDATE needs at least one assignment to return the existing row
+            let column = target.split(',').next().unwrap_or(target).trim();
+            let fallback = format!("{column} = EXCLUDED.{column}");
+            quote! {
+                if assignments.is_empty() {
+                    assignments.push(#fallback);
+                }
+                let on_conflict = format!(
+                    " ON CONFLICT ({}) DO UPDATE SET {}",
+                    #target,
+                    assignments.join(", ")
+                );
+            }
+        }
+        None => quote! { let on_conflict = ""; },
+    };
+    let assignments =
+        conflict_target.map(|_| quote! { let mut assignments: Vec<&str> = Vec::new(); });
+
     quote! {
-        impl ::georm::Defaultable<#id_type, #struct_name> for #defaultable_struct_name {
-            async fn create(&self, pool: &::sqlx::PgPool) -> ::sqlx::Result<#struct_name> {
-                let mut dynamic_fields = Vec::new();
+        let _ = |entity: &#struct_name| {
+            let _ = ::sqlx::query!(#check #(, #check_args)*).try_map(#from_record);
+        };
+        let mut columns: Vec<&str> = Vec::new();
+        let mut values: Vec<String> = Vec::new();
+        let mut bound_count = 0;
+        #assignments
+
+        #(#column_checks)*
+
+        #on_conflict
+        let query = if columns.is_empty() {
+            format!(
+                "INSERT INTO {} DEFAULT VALUES{} RETURNING {}",
+                #table,
+                on_conflict,
+                #returning
+            )
+        } else {
+            format!(
+                "INSERT INTO {} ({}) VALUES ({}){} RETURNING {}",
+                #table,
+                columns.join(", "),
+                values.join(", "),
+                on_conflict,
+                #returning
+            )
+        };
 
-                #(#field_checks)*
+        let mut query_builder = ::sqlx::query_as::<_, #struct_name>(&query);
+        #(#bind_checks)*
+        query_builder.fetch_one(executor).await
+    }
+}
 
-                let mut all_fields = vec![#(#static_field_names),*];
-                all_fields.extend(dynamic_fields);
+fn generate_defaultable_trait_impl(
+    struct_name: &syn::Ident,
+    defaultable_struct_name: &syn::Ident,
+    struct_attrs: &GeormStructAttributes,
+    fields: &[GeormField],
+    id: &IdType,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let table = &struct_attrs.table;
 
-                let placeholders: Vec<String> = (1..=all_fields.len())
-                    .map(|i| format!("${}", i))
-                    .collect();
+    // Composite keys are identified by their generated ID struct, and upserts
+    // conflict on them unless another target is given
+    let (id_type, primary_key) = match id {
+        IdType::Simple {
+            field_type, column, ..
+        } => (quote! { #field_type }, column.clone()),
+        IdType::Composite { fields, field_type } => (
+            quote! { #field_type },
+            fields
+                .iter()
+                .map(|field| field.column.clone())
+                .collect::<Vec<String>>()
+                .join(", "),
+        ),
+    };
+    let conflict_target = struct_attrs.on_conflict.as_deref().unwrap_or(&primary_key);
 
-                let query = format!(
-                    "INSERT INTO {} ({}) VALUES ({}) RETURNING *",
-                    #table,
-                    all_fields.join(", "),
-                    placeholders.join(", ")
-                );
+    let defaultable_fields: Vec<&GeormField> = fields.iter().filter(|f| f.defaultable).collect();
+    let defaultable_idents: Vec<&syn::Ident> =
+        defaultable_fields.iter().map(|f| &f.ident).collect();
 
-                let mut query_builder = ::sqlx::query_as::<_, #struct_name>(&query);
+    // One checked query per combination of provided defaultable fields, so that
+    // omitted ones are left out of the INSERT and get their database default
+    let arms = |conflict_target: Option<&str>| {
+        (0..1 << defaultable_fields.len())
+            .map(|provided| {
+                generate_insert_arm(
+                    table,
+                    fields,
+                    &defaultable_fields,
+                    provided,
+                    conflict_target,
+                    from_record,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let body = |conflict_target: Option<&str>| {
+        if defaultable_fields.len() > MAX_CHECKED_DEFAULTABLE_FIELDS {
+            return generate_runtime_insert(
+                table,
+                struct_name,
+                fields,
+                conflict_target,
+                from_record,
+            );
+        }
+        let arms = arms(conflict_target);
+        quote! {
+            match (#(&self.#defaultable_idents,)*) {
+                #(#arms)*
+            }
+        }
+    };
+    let create_body = body(None);
+    let upsert_body = body(Some(conflict_target));
 
-                // Bind non-defaultable fields first
-                #(query_builder = query_builder.bind(&self.#static_field_idents);)*
+    quote! {
+        impl ::georm::Defaultable<#id_type, #struct_name> for #defaultable_struct_name {
+            async fn create<'e, E>(&self, executor: E) -> ::sqlx::Result<#struct_name>
+            where
+                E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+            {
+                #create_body
+            }
 
-                // Then bind defaultable fields that have values
-                #(#bind_checks)*
+            async fn create_or_update<'e, E>(&self, executor: E) -> ::sqlx::Result<#struct_name>
+            where
+                E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+            {
+                #upsert_body
+            }
+        }
+    }
+}
 
-                query_builder.fetch_one(pool).await
+/// `From<Entity>` implementation, providing every defaultable field.
+fn generate_from_entity(
+    struct_name: &syn::Ident,
+    defaultable_struct_name: &syn::Ident,
+    fields: &[&GeormField],
+) -> proc_macro2::TokenStream {
+    let field_values = fields.iter().map(|field| {
+        let ident = &field.ident;
+        match (field.defaultable, field.is_nullable()) {
+            (true, true) => quote! { #ident: ::georm::DefaultOr::Value(entity.#ident) },
+            (true, false) => quote! { #ident: Some(entity.#ident) },
+            (false, _) => quote! { #ident: entity.#ident },
+        }
+    });
+    quote! {
+        impl From<#struct_name> for #defaultable_struct_name {
+            fn from(entity: #struct_name) -> Self {
+                Self {
+                    #(#field_values),*
+                }
+            }
+        }
+    }
+}
+
+/// `Default` implementation, leaving defaultable fields to their database
+/// default. Since the macro cannot tell which types implement `Default`, the
+/// other fields are bound with higher-ranked bounds, which make the
+/// implementation not apply instead of failing to compile when one is unmet.
+fn generate_default_impl(
+    defaultable_struct_name: &syn::Ident,
+    fields: &[&GeormField],
+) -> proc_macro2::TokenStream {
+    let bounds: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .filter(|f| !f.defaultable)
+        .map(|field| {
+            let ty = &field.ty;
+            quote! { for<'georm> #ty: ::core::default::Default }
+        })
+        .collect();
+    let where_clause = if bounds.is_empty() {
+        quote! {}
+    } else {
+        quote! { where #(#bounds),* }
+    };
+    let field_values = fields.iter().map(|field| {
+        let ident = &field.ident;
+        quote! { #ident: ::core::default::Default::default() }
+    });
+    quote! {
+        impl ::core::default::Default for #defaultable_struct_name #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#field_values),*
+                }
             }
         }
     }
@@ -117,6 +456,7 @@ pub fn derive_defaultable_struct(
     ast: &syn::DeriveInput,
     struct_attrs: &GeormStructAttributes,
     fields: &[GeormField],
+    id: &IdType,
 ) -> proc_macro2::TokenStream {
     // Only generate if there are defaultable fields
     if fields.iter().all(|field| !field.defaultable) {
@@ -124,24 +464,59 @@ pub fn derive_defaultable_struct(
     }
 
     let struct_name = &ast.ident;
+    let from_record = super::traits::entity_from_record(&quote! { #struct_name }, fields);
+
+    // Skipped fields are not stored, the created entity gets their default value
+    let fields: Vec<GeormField> = fields.iter().filter(|f| !f.skip).cloned().collect();
+    let fields = fields.as_slice();
+
     let vis = &ast.vis;
     let defaultable_struct_name = quote::format_ident!("{}Default", struct_name);
 
-    let defaultable_fields: Vec<proc_macro2::TokenStream> =
-        fields.iter().map(create_defaultable_field).collect();
+    let struct_fields: Vec<&GeormField> = fields
+        .iter()
+        .filter(|f| !f.generated && f.sql_value().is_none())
+        .collect();
+    let defaultable_fields: Vec<proc_macro2::TokenStream> = struct_fields
+        .iter()
+        .map(|field| create_defaultable_field(field))
+        .collect();
+
+    let derives = &struct_attrs.default_derive;
+    if derives
+        .iter()
+        .any(|path| path.segments.last().is_some_and(|s| s.ident == "Default"))
+    {
+        panic!(
+            "Default is implemented on {defaultable_struct_name} when possible and cannot be \
+            derived. Remove it from default_derive."
+        );
+    }
+    let derive_attribute = if derives.is_empty() {
+        quote! {}
+    } else {
+        quote! { #[derive(#(#derives),*)] }
+    };
+    let from_entity = generate_from_entity(struct_name, &defaultable_struct_name, &struct_fields);
+    let default_impl = generate_default_impl(&defaultable_struct_name, &struct_fields);
 
     let trait_impl = generate_defaultable_trait_impl(
         struct_name,
         &defaultable_struct_name,
         struct_attrs,
         fields,
+        id,
+        &from_record,
     );
 
     quote! {
+        #derive_attribute
         #vis struct #defaultable_struct_name {
             #(#defaultable_fields),*
         }
 
         #trait_impl
+        #from_entity
+        #default_impl
     }
 }
@@ -15,6 +15,8 @@ pub struct M2MRelationship {
     #[deluxe(default = String::from("id"))]
     pub remote_id: String,
     pub link: M2MLink,
+    #[deluxe(default = None)]
+    pub order_by: Option<String>,
 }
 
 pub struct Identifier {
@@ -28,6 +30,7 @@ pub struct M2MRelationshipComplete {
     pub local: Identifier,
     pub remote: Identifier,
     pub link: M2MLink,
+    pub order_by: Option<String>,
 }
 
 impl M2MRelationshipComplete {
@@ -36,6 +39,7 @@ impl M2MRelationshipComplete {
             name: other.name.clone(),
             entity: other.entity.clone(),
             link: other.link.clone(),
+            order_by: other.order_by.clone(),
             local: Identifier {
                 table: local_table.to_string(),
                 id: local_id.to_string(),
@@ -55,24 +59,37 @@ impl From<&M2MRelationshipComplete> for proc_macro2::TokenStream {
             proc_macro2::Span::call_site(),
         );
         let entity = &value.entity;
-        let query = format!(
-            "SELECT remote.*
-FROM {} local
-JOIN {} link ON link.{} = local.{}
-JOIN {} remote ON link.{} = remote.{}
-WHERE local.{} = $1",
+        let from_where = format!(
+            "FROM {} remote
+WHERE remote.{} IN (
+    SELECT link.{}
+    FROM {} local
+    JOIN {} link ON link.{} = local.{}
+    WHERE local.{} = $1
+)",
+            value.remote.table,
+            value.remote.id,
+            value.link.to,
             value.local.table,
             value.link.table,
             value.link.from,
             value.local.id,
-            value.remote.table,
-            value.link.to,
-            value.remote.id,
             value.local.id
         );
+        let body = super::relationship_getter_body(
+            entity,
+            &from_where,
+            value.order_by.as_deref(),
+            false,
+            &quote! { self.get_id() },
+            &quote! { fetch_all },
+        );
         quote! {
-            pub async fn #function(&self, pool: &::sqlx::PgPool) -> ::sqlx::Result<Vec<#entity>> {
-                ::sqlx::query_as!(#entity, #query, self.get_id()).fetch_all(pool).await
+            pub async fn #function<'e, E>(&self, executor: E) -> ::sqlx::Result<Vec<#entity>>
+            where
+                E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+            {
+                #body
             }
         }
     }
@@ -1,4 +1,5 @@
 use quote::quote;
+use syn::ext::IdentExt;
 
 pub mod simple_relationship;
 use simple_relationship::{OneToMany, OneToOne, SimpleRelationship};
@@ -6,10 +7,109 @@ use simple_relationship::{OneToMany, OneToOne, SimpleRelationship};
 pub mod m2m_relationship;
 use m2m_relationship::M2MRelationship;
 
+pub mod rename_rule;
+use rename_rule::RenameRule;
+
+/// Maximum length in bytes of a PostgreSQL identifier.
+const MAX_IDENTIFIER_LENGTH: usize = 63;
+
+/// Name of a type as written in `query!` type overrides, without the spaces
+/// added when stringifying tokens so that it fits in a column alias.
+fn type_name(ty: &syn::Type) -> String {
+    let tokens = quote! { #ty }.to_string();
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let chars: Vec<char> = tokens.chars().collect();
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| {
+            **c != ' '
+                || (*i > 0
+                    && chars.get(i + 1).is_some_and(|next| is_word(*next))
+                    && is_word(chars[i - 1]))
+        })
+        .map(|(_, c)| *c)
+        .collect()
+}
+
+/// Expression building a query selecting every column of the rows of `entity`
+/// matching `from_where`, a `FROM ... WHERE ...` clause, leaving out soft-deleted
+/// ones unless `with_deleted` is set. They are sorted by `order_by` if given, and
+/// by the entity's own `order_by` otherwise. The columns, soft delete condition
+/// and default order are only known to the entity's own derive.
+fn select_entity_query(
+    entity: &syn::Type,
+    from_where: &str,
+    order_by: Option<&str>,
+    with_deleted: bool,
+) -> proc_macro2::TokenStream {
+    let order_by = match order_by {
+        Some(order_by) => {
+            let order_by = format!(" ORDER BY {order_by}");
+            quote! { #order_by }
+        }
+        None => quote! { <#entity>::GEORM_ORDER_BY },
+    };
+    let not_deleted = if with_deleted {
+        quote! { "TRUE" }
+    } else {
+        quote! { <#entity>::GEORM_NOT_DELETED }
+    };
+    quote! {
+        format!(
+            "SELECT {} {} AND {}{}",
+            <#entity>::GEORM_COLUMNS,
+            #from_where,
+            #not_deleted,
+            #order_by
+        )
+    }
+}
+
+/// Body of a relationship getter fetching the rows of `entity` matching
+/// `from_where` with `fetch`, its `$1` parameter being `value`. Soft-deleted rows
+/// are left out unless `with_deleted` is set.
+///
+/// Since the columns of `entity` are only known to its own derive, the query is
+/// built at runtime from its constants, once per getter, and its rows are decoded
+/// through its `FromRow` implementation. The tables and columns of the
+/// relationship are still checked at compile time by a `query!` that never runs,
+/// but the columns of `entity` and the decoding of its rows no longer are: a
+/// mismatch between `entity` and its table is an error when the getter runs.
+pub fn relationship_getter_body(
+    entity: &syn::Type,
+    from_where: &str,
+    order_by: Option<&str>,
+    with_deleted: bool,
+    value: &proc_macro2::TokenStream,
+    fetch: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let query = select_entity_query(entity, from_where, order_by, with_deleted);
+    let check = format!("SELECT 1 AS georm_checked {from_where}");
+    quote! {
+        static QUERY: ::std::sync::LazyLock<String> = ::std::sync::LazyLock::new(|| #query);
+        let _ = || ::sqlx::query!(#check, #value);
+        ::sqlx::query_as::<_, #entity>(QUERY.as_str())
+            .bind(&#value)
+            .#fetch(executor)
+            .await
+    }
+}
+
 #[derive(deluxe::ExtractAttributes)]
 #[deluxe(attributes(georm))]
 pub struct GeormStructAttributes {
     pub table: String,
+    #[deluxe(default = None)]
+    pub order_by: Option<String>,
+    #[deluxe(default = None)]
+    pub rename_all: Option<RenameRule>,
+    #[deluxe(default = None)]
+    pub on_conflict: Option<String>,
+    #[deluxe(default = Vec::new())]
+    pub default_derive: Vec<syn::Path>,
+    #[deluxe(default = false)]
+    pub patch: bool,
     #[deluxe(default = Vec::new())]
     pub one_to_one: Vec<SimpleRelationship<OneToOne>>,
     #[deluxe(default = Vec::new())]
@@ -27,6 +127,28 @@ struct GeormFieldAttributes {
     pub relation: Option<O2ORelationship>,
     #[deluxe(default = false)]
     pub defaultable: bool,
+    #[deluxe(default = None)]
+    pub column: Option<String>,
+    #[deluxe(default = false)]
+    pub skip: bool,
+    #[deluxe(default = None)]
+    pub default: Option<syn::Path>,
+    #[deluxe(default = false)]
+    pub json: bool,
+    #[deluxe(default = None)]
+    pub sql_type: Option<String>,
+    #[deluxe(default = false)]
+    pub generated: bool,
+    #[deluxe(default = false)]
+    pub immutable: bool,
+    #[deluxe(default = false)]
+    pub created_at: bool,
+    #[deluxe(default = false)]
+    pub updated_at: bool,
+    #[deluxe(default = false)]
+    pub version: bool,
+    #[deluxe(default = false)]
+    pub soft_delete: bool,
 }
 
 #[derive(deluxe::ParseMetaItem, Clone, Debug)]
@@ -48,10 +170,31 @@ pub struct GeormField {
     pub id: bool,
     pub relation: Option<O2ORelationship>,
     pub defaultable: bool,
+    /// Column name, when it differs from the field's name
+    pub column: Option<String>,
+    pub skip: bool,
+    /// Function providing the value of a skipped field
+    pub default: Option<syn::Path>,
+    /// Whether the field is stored as JSON through `sqlx::types::Json`
+    pub json: bool,
+    /// PostgreSQL type the column is cast to when read
+    pub sql_type: Option<String>,
+    /// Whether the column is generated by the database and never written
+    pub generated: bool,
+    /// Whether the column is only written on insertion
+    pub immutable: bool,
+    /// Whether the column is set to `now()` on insertion
+    pub created_at: bool,
+    /// Whether the column is set to `now()` on every write
+    pub updated_at: bool,
+    /// Whether the column holds the version used for optimistic locking
+    pub version: bool,
+    /// Whether the column holds the deletion time of soft-deleted rows
+    pub soft_delete: bool,
 }
 
 impl GeormField {
-    pub fn new(field: &mut syn::Field) -> Self {
+    pub fn new(field: &mut syn::Field, rename_all: Option<RenameRule>) -> Self {
         let ident = field.clone().ident.unwrap();
         let ty = field.clone().ty;
         let attrs: GeormFieldAttributes =
@@ -60,13 +203,110 @@ impl GeormField {
             id,
             relation,
             defaultable,
+            column,
+            skip,
+            default,
+            json,
+            sql_type,
+            generated,
+            immutable,
+            created_at,
+            updated_at,
+            version,
+            soft_delete,
         } = attrs;
+        // Raw identifiers such as `r#type` are mapped to their unprefixed column
+        let column = column
+            .or_else(|| rename_all.map(|rule| rule.apply(&ident.unraw().to_string())))
+            .or_else(|| {
+                ident
+                    .to_string()
+                    .starts_with("r#")
+                    .then(|| ident.unraw().to_string())
+            });
 
-        // Validate that defaultable is not used on Option<T> fields
-        if defaultable && Self::is_option_type(&ty) {
+        if skip && (id || relation.is_some() || defaultable) {
+            panic!(
+                "Field '{}' is skipped and cannot be an id, a relation, or defaultable.",
+                ident
+            );
+        }
+        if json && (id || skip) {
+            panic!(
+                "Field '{}' is stored as JSON and cannot be an id or skipped.",
+                ident
+            );
+        }
+        if sql_type.is_some() && (json || skip) {
+            panic!(
+                "Field '{}' has an SQL type and cannot be stored as JSON or skipped.",
+                ident
+            );
+        }
+        if generated && (skip || defaultable) {
+            panic!(
+                "Field '{}' is generated and cannot be skipped or defaultable.",
+                ident
+            );
+        }
+        if immutable && (skip || generated) {
+            panic!(
+                "Field '{}' is immutable and cannot be skipped or generated.",
+                ident
+            );
+        }
+        if (created_at || updated_at)
+            && (created_at == updated_at
+                || id
+                || skip
+                || defaultable
+                || json
+                || generated
+                || immutable)
+        {
+            panic!(
+                "Field '{}' is a timestamp and cannot be another timestamp, an id, \
+                skipped, defaultable, JSON, generated, or immutable.",
+                ident
+            );
+        }
+        if version
+            && (id
+                || skip
+                || json
+                || generated
+                || immutable
+                || created_at
+                || updated_at
+                || sql_type.is_some())
+        {
+            panic!(
+                "Field '{}' is a version and cannot be an id, skipped, JSON, generated, \
+                immutable, a timestamp, or have an SQL type.",
+                ident
+            );
+        }
+        if soft_delete
+            && (!Self::is_option_type(&ty)
+                || id
+                || skip
+                || defaultable
+                || json
+                || generated
+                || created_at
+                || updated_at
+                || version)
+        {
+            panic!(
+                "Field '{}' marks soft deletes and must be an Option<T> that is not an id, \
+                skipped, defaultable, JSON, generated, a timestamp, or a version.",
+                ident
+            );
+        }
+        if default.is_some() && !skip {
             panic!(
-                "Field '{}' is already an Option<T> and cannot be marked as defaultable. \
-                Remove the #[georm(defaultable)] attribute.",
+                "Field '{}' has a default value but is not skipped. \
+                Add the #[georm(skip)] attribute.",
                 ident
             );
         }
@@ -78,9 +318,217 @@ impl GeormField {
             ty,
             relation,
             defaultable,
+            column,
+            skip,
+            default,
+            json,
+            sql_type,
+            generated,
+            immutable,
+            created_at,
+            updated_at,
+            version,
+            soft_delete,
         }
     }
 
+    /// SQL expression the column is written with instead of the field's value.
+    pub fn sql_value(&self) -> Option<&'static str> {
+        (self.created_at || self.updated_at).then_some("now()")
+    }
+
+    /// Whether the column is written with the field's value when updating an
+    /// existing row. Versions are incremented instead, and soft deletes are only
+    /// written by `delete` and `restore`.
+    pub fn is_updatable(&self) -> bool {
+        !(self.id
+            || self.generated
+            || self.immutable
+            || self.created_at
+            || self.version
+            || self.soft_delete)
+    }
+
+    /// Expression filling a skipped field when reading a row.
+    pub fn default_value(&self) -> proc_macro2::TokenStream {
+        match &self.default {
+            Some(path) => quote! { #path() },
+            None => quote! { ::core::default::Default::default() },
+        }
+    }
+
+    /// Name of the column in the database, as found in query results.
+    pub fn column_name(&self) -> String {
+        match &self.column {
+            Some(column) => column.clone(),
+            None => self.ident.to_string(),
+        }
+    }
+
+    /// Column identifier to use in SQL. Renamed columns are quoted so that
+    /// their case is preserved and reserved words such as `type` are allowed.
+    pub fn column(&self) -> String {
+        match &self.column {
+            Some(column) => format!("\"{}\"", column.replace('"', "\"\"")),
+            None => self.ident.to_string(),
+        }
+    }
+
+    /// Column as read from the database, cast to its `sql_type` if any.
+    fn read_column(&self) -> String {
+        match &self.sql_type {
+            Some(sql_type) => format!("{}::{sql_type}", self.column()),
+            None => self.column(),
+        }
+    }
+
+    /// Column as a select list item for `query!` queries, aliased to the field's
+    /// name with the field's type as override, so that custom types such as
+    /// enums can be read. Its nullability also follows the field, as it cannot be
+    /// inferred through casts. JSON columns are read as `JsonValue`.
+    ///
+    /// PostgreSQL truncates identifiers longer than 63 bytes, so a type override
+    /// that does not fit is left out and the column keeps the type inferred by
+    /// SQLx, which is the field's type for built-in types.
+    pub fn select_column(&self) -> String {
+        let name = self.ident.unraw();
+        let nullability = if Self::is_option_type(&self.ty) {
+            "?"
+        } else {
+            "!"
+        };
+        let typed = (!self.json).then(|| {
+            let ty = Self::option_inner_type(&self.ty).unwrap_or(&self.ty);
+            format!("{name}{nullability}: {}", type_name(ty))
+        });
+        let alias = typed
+            .filter(|alias| alias.len() <= MAX_IDENTIFIER_LENGTH)
+            .unwrap_or_else(|| format!("{name}{nullability}"));
+        format!("{} AS \"{alias}\"", self.read_column())
+    }
+
+    /// Column as a select list item for runtime queries decoded through `FromRow`.
+    pub fn row_column(&self) -> String {
+        match &self.sql_type {
+            Some(_) => format!("{} AS {}", self.read_column(), self.column()),
+            None => self.column(),
+        }
+    }
+
+    /// Parameter `$index` holding an array of the field's values, as passed to
+    /// `UNNEST` whose arguments PostgreSQL cannot infer the type of. It is cast to
+    /// an array of the field's `sql_type`, or of `jsonb` for JSON fields, and
+    /// otherwise takes the type of an array of the column of `table`, which also
+    /// covers custom types such as enums.
+    pub fn array_param(&self, index: usize, table: &str) -> String {
+        if self.json {
+            format!("${index}::jsonb[]")
+        } else if let Some(sql_type) = &self.sql_type {
+            format!("${index}::{sql_type}[]")
+        } else {
+            format!(
+                "COALESCE(${index}, ARRAY[(NULL::{table}).{}])",
+                self.column()
+            )
+        }
+    }
+
+    /// `sqlx::types::Json` wrapper of the field's type, without its `Option`.
+    fn json_type(&self) -> proc_macro2::TokenStream {
+        let ty = Self::option_inner_type(&self.ty).unwrap_or(&self.ty);
+        quote! { ::sqlx::types::Json<#ty> }
+    }
+
+    /// Value of the field read from a `query!` record, within a closure
+    /// returning `sqlx::Result`.
+    pub fn record_value(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        match (self.json, Self::is_option_type(&self.ty)) {
+            (true, true) => quote! { record.#ident.map(::georm::json::decode).transpose()? },
+            (true, false) => quote! { ::georm::json::decode(record.#ident)? },
+            (false, _) => quote! { record.#ident },
+        }
+    }
+
+    /// Value of the field read from a row in `FromRow`.
+    pub fn row_value(&self) -> proc_macro2::TokenStream {
+        let column = self.column_name();
+        if !self.json {
+            return quote! { row.try_get(#column)? };
+        }
+        let json_type = self.json_type();
+        if Self::is_option_type(&self.ty) {
+            quote! { row.try_get::<Option<#json_type>, _>(#column)?.map(|json| json.0) }
+        } else {
+            quote! { row.try_get::<#json_type, _>(#column)?.0 }
+        }
+    }
+
+    /// Argument of a `query!` macro passing `value`, the field's value. Its type
+    /// is overridden with the field's type so that custom types can be bound.
+    pub fn query_arg(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if self.json {
+            let value = self.bind_value(value);
+            let bind_type = self.bind_type();
+            quote! { #value as #bind_type }
+        } else {
+            let ty = &self.ty;
+            quote! { &#value as &#ty }
+        }
+    }
+
+    /// Reference to `value`, the field's value, that can be bound to a query.
+    pub fn bind_value(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match (self.json, Self::is_option_type(&self.ty)) {
+            (true, true) => quote! { #value.as_ref().map(::sqlx::types::Json) },
+            (true, false) => quote! { ::sqlx::types::Json(&#value) },
+            (false, _) => quote! { &#value },
+        }
+    }
+
+    /// Type of the reference returned by `bind_value`.
+    pub fn bind_type(&self) -> proc_macro2::TokenStream {
+        let ty = &self.ty;
+        match (self.json, Self::is_option_type(&self.ty)) {
+            (true, true) => {
+                let json_type = self.json_type_ref();
+                quote! { Option<#json_type> }
+            }
+            (true, false) => self.json_type_ref(),
+            (false, _) => quote! { &#ty },
+        }
+    }
+
+    /// `sqlx::types::Json` wrapper of a reference to the field's type, without
+    /// its `Option`.
+    fn json_type_ref(&self) -> proc_macro2::TokenStream {
+        let ty = Self::option_inner_type(&self.ty).unwrap_or(&self.ty);
+        quote! { ::sqlx::types::Json<&#ty> }
+    }
+
+    /// Get `T` out of an `Option<T>` type
+    fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+        let syn::Type::Path(type_path) = ty else {
+            return None;
+        };
+        let segment = type_path.path.segments.last()?;
+        if segment.ident != "Option" {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+            return None;
+        };
+        match arguments.args.first()? {
+            syn::GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// Whether the field is an `Option<T>`, stored in a nullable column.
+    pub fn is_nullable(&self) -> bool {
+        Self::is_option_type(&self.ty)
+    }
+
     /// Check if a type is Option<T>
     fn is_option_type(ty: &syn::Type) -> bool {
         match ty {
@@ -111,19 +559,29 @@ impl From<&GeormField> for proc_macro2::TokenStream {
         } else {
             quote! { #entity }
         };
-        let query = format!(
-            "SELECT * FROM {} WHERE {} = $1",
-            relation.table, relation.remote_id
-        );
+        let from_where = format!("FROM {} WHERE {} = $1", relation.table, relation.remote_id);
         let local_ident = &value.field.ident;
         let fetch = if relation.nullable {
             quote! { fetch_optional }
         } else {
             quote! { fetch_one }
         };
+        // A required link has no way to report a soft-deleted row as missing, so
+        // it is returned anyway
+        let body = relationship_getter_body(
+            entity,
+            &from_where,
+            None,
+            !relation.nullable,
+            &quote! { self.#local_ident },
+            &fetch,
+        );
         quote! {
-            pub async fn #function(&self, pool: &::sqlx::PgPool) -> ::sqlx::Result<#return_type> {
-                ::sqlx::query_as!(#entity, #query, self.#local_ident).#fetch(pool).await
+            pub async fn #function<'e, E>(&self, executor: E) -> ::sqlx::Result<#return_type>
+            where
+                E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+            {
+                #body
             }
         }
     }
@@ -0,0 +1,69 @@
+use heck::{
+    ToKebabCase, ToLowerCamelCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase,
+    ToUpperCamelCase,
+};
+
+/// Case convention applied to field names to get their column name, set
+/// with `#[georm(rename_all = "...")]`.
+#[derive(Debug, Clone, Copy)]
+pub enum RenameRule {
+    Lowercase,
+    Uppercase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+impl RenameRule {
+    const VARIANTS: [(&'static str, Self); 8] = [
+        ("lowercase", Self::Lowercase),
+        ("UPPERCASE", Self::Uppercase),
+        ("PascalCase", Self::PascalCase),
+        ("camelCase", Self::CamelCase),
+        ("snake_case", Self::SnakeCase),
+        ("SCREAMING_SNAKE_CASE", Self::ScreamingSnakeCase),
+        ("kebab-case", Self::KebabCase),
+        ("SCREAMING-KEBAB-CASE", Self::ScreamingKebabCase),
+    ];
+
+    pub fn apply(self, field: &str) -> String {
+        match self {
+            Self::Lowercase => field.to_lowercase(),
+            Self::Uppercase => field.to_uppercase(),
+            Self::PascalCase => field.to_upper_camel_case(),
+            Self::CamelCase => field.to_lower_camel_case(),
+            Self::SnakeCase => field.to_snake_case(),
+            Self::ScreamingSnakeCase => field.to_shouty_snake_case(),
+            Self::KebabCase => field.to_kebab_case(),
+            Self::ScreamingKebabCase => field.to_shouty_kebab_case(),
+        }
+    }
+}
+
+impl deluxe::ParseMetaItem for RenameRule {
+    fn parse_meta_item(
+        input: syn::parse::ParseStream,
+        _mode: deluxe::ParseMode,
+    ) -> deluxe::Result<Self> {
+        let lit: syn::LitStr = input.parse()?;
+        let value = lit.value();
+        Self::VARIANTS
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| {
+                let expected = Self::VARIANTS
+                    .iter()
+                    .map(|(name, _)| format!("\"{name}\""))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                syn::Error::new(
+                    lit.span(),
+                    format!("unknown rename rule \"{value}\", expected one of {expected}"),
+                )
+            })
+    }
+}
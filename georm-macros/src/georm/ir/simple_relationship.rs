@@ -19,6 +19,8 @@ where
     pub remote_id: String,
     pub table: String,
     pub entity: syn::Type,
+    #[deluxe(default = None)]
+    pub order_by: Option<String>,
     #[deluxe(default = T::default())]
     _phantom: T,
 }
@@ -27,8 +29,17 @@ impl<T> SimpleRelationship<T>
 where
     T: SimpleRelationshipType + deluxe::ParseMetaItem + Default,
 {
-    pub fn make_query(&self) -> String {
-        format!("SELECT * FROM {} WHERE {} = $1", self.table, self.remote_id)
+    /// Body of the getter, fetching the related entities with `fetch`.
+    pub fn make_body(&self, fetch: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let from_where = format!("FROM {} WHERE {} = $1", self.table, self.remote_id);
+        super::relationship_getter_body(
+            &self.entity,
+            &from_where,
+            self.order_by.as_deref(),
+            false,
+            &quote! { self.get_id() },
+            fetch,
+        )
     }
 
     pub fn make_function_name(&self) -> syn::Ident {
@@ -41,12 +52,15 @@ where
 
 impl From<&SimpleRelationship<OneToOne>> for proc_macro2::TokenStream {
     fn from(value: &SimpleRelationship<OneToOne>) -> Self {
-        let query = value.make_query();
+        let body = value.make_body(&quote! { fetch_optional });
         let entity = &value.entity;
         let function = value.make_function_name();
         quote! {
-            pub async fn #function(&self, pool: &::sqlx::PgPool) -> ::sqlx::Result<Option<#entity>> {
-                ::sqlx::query_as!(#entity, #query, self.get_id()).fetch_optional(pool).await
+            pub async fn #function<'e, E>(&self, executor: E) -> ::sqlx::Result<Option<#entity>>
+            where
+                E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+            {
+                #body
             }
         }
     }
@@ -54,12 +68,15 @@ impl From<&SimpleRelationship<OneToOne>> for proc_macro2::TokenStream {
 
 impl From<&SimpleRelationship<OneToMany>> for proc_macro2::TokenStream {
     fn from(value: &SimpleRelationship<OneToMany>) -> Self {
-        let query = value.make_query();
+        let body = value.make_body(&quote! { fetch_all });
         let entity = &value.entity;
         let function = value.make_function_name();
         quote! {
-            pub async fn #function(&self, pool: &::sqlx::PgPool) -> ::sqlx::Result<Vec<#entity>> {
-                ::sqlx::query_as!(#entity, #query, self.get_id()).fetch_all(pool).await
+            pub async fn #function<'e, E>(&self, executor: E) -> ::sqlx::Result<Vec<#entity>>
+            where
+                E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+            {
+                #body
             }
         }
     }
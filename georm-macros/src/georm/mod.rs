@@ -1,14 +1,19 @@
 use quote::quote;
 
+mod builder;
 mod composite_keys;
 mod defaultable_struct;
 mod ir;
+mod patch_struct;
 pub(crate) use ir::GeormField;
 mod relationships;
 mod traits;
 pub(crate) use composite_keys::IdType;
 
-fn extract_georm_field_attrs(ast: &mut syn::DeriveInput) -> deluxe::Result<Vec<GeormField>> {
+fn extract_georm_field_attrs(
+    ast: &mut syn::DeriveInput,
+    struct_attrs: &ir::GeormStructAttributes,
+) -> deluxe::Result<Vec<GeormField>> {
     let syn::Data::Struct(s) = &mut ast.data else {
         return Err(syn::Error::new_spanned(
             ast,
@@ -19,7 +24,7 @@ fn extract_georm_field_attrs(ast: &mut syn::DeriveInput) -> deluxe::Result<Vec<G
         .fields
         .clone()
         .into_iter()
-        .map(|mut field| GeormField::new(&mut field))
+        .map(|mut field| GeormField::new(&mut field, struct_attrs.rename_all))
         .collect::<Vec<GeormField>>();
     let identifiers: Vec<GeormField> = fields
         .clone()
@@ -31,6 +36,19 @@ fn extract_georm_field_attrs(ast: &mut syn::DeriveInput) -> deluxe::Result<Vec<G
             ast,
             "Struct {name} must have one identifier",
         ))
+    } else if fields.iter().filter(|field| field.version).count() > 1 {
+        Err(syn::Error::new_spanned(
+            &*ast,
+            format!("Struct {} must have at most one version field", ast.ident),
+        ))
+    } else if fields.iter().filter(|field| field.soft_delete).count() > 1 {
+        Err(syn::Error::new_spanned(
+            &*ast,
+            format!(
+                "Struct {} must have at most one soft delete field",
+                ast.ident
+            ),
+        ))
     } else {
         Ok(fields)
     }
@@ -42,23 +60,35 @@ pub fn georm_derive_macro2(
     let mut ast: syn::DeriveInput = syn::parse2(item).expect("Failed to parse input");
     let struct_attrs: ir::GeormStructAttributes =
         deluxe::extract_attributes(&mut ast).expect("Could not extract attributes from struct");
-    let fields = extract_georm_field_attrs(&mut ast)?;
-    let defaultable_struct =
-        defaultable_struct::derive_defaultable_struct(&ast, &struct_attrs, &fields);
+    let fields = extract_georm_field_attrs(&mut ast, &struct_attrs)?;
     let from_row_impl = generate_from_row_impl(&ast, &fields);
+    let columns_const = generate_columns_const(&ast, &struct_attrs, &fields);
 
     let (identifier, id_struct) = composite_keys::create_primary_key(&ast, &fields);
+    let defaultable_struct =
+        defaultable_struct::derive_defaultable_struct(&ast, &struct_attrs, &fields, &identifier);
+    let builder = builder::derive_builder(&ast, &fields);
+    let patch_struct = patch_struct::derive_patch_struct(&ast, &struct_attrs, &fields, &identifier);
 
     let relationships =
         relationships::derive_relationships(&ast, &struct_attrs, &fields, &identifier);
-    let trait_impl = traits::derive_trait(&ast, &struct_attrs.table, &fields, &identifier);
+    let trait_impl = traits::derive_trait(&ast, &struct_attrs, &fields, &identifier);
+    let versioned_methods =
+        traits::derive_versioned_methods(&ast, &struct_attrs, &fields, &identifier);
+    let soft_delete_methods =
+        traits::derive_soft_delete_methods(&ast, &struct_attrs, &fields, &identifier);
 
     let code = quote! {
         #id_struct
         #defaultable_struct
+        #builder
+        #patch_struct
         #relationships
         #trait_impl
+        #versioned_methods
+        #soft_delete_methods
         #from_row_impl
+        #columns_const
     };
     Ok(code)
 }
@@ -68,17 +98,57 @@ fn generate_from_row_impl(
     fields: &[GeormField],
 ) -> proc_macro2::TokenStream {
     let struct_name = &ast.ident;
-    let field_idents: Vec<&syn::Ident> = fields.iter().map(|f| &f.ident).collect();
-    let field_names: Vec<String> = fields.iter().map(|f| f.ident.to_string()).collect();
+    let field_inits: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            if field.skip {
+                let default = field.default_value();
+                quote! { #ident: #default }
+            } else {
+                let value = field.row_value();
+                quote! { #ident: #value }
+            }
+        })
+        .collect();
 
     quote! {
         impl<'r> ::sqlx::FromRow<'r, ::sqlx::postgres::PgRow> for #struct_name {
             fn from_row(row: &'r ::sqlx::postgres::PgRow) -> ::sqlx::Result<Self> {
                 use ::sqlx::Row;
                 Ok(Self {
-                    #(#field_idents: row.try_get(#field_names)?),*
+                    #(#field_inits),*
                 })
             }
         }
     }
 }
+
+/// Columns read by `FromRow`, condition leaving out soft-deleted rows and default
+/// order, so that other entities' relationship getters can select them explicitly.
+fn generate_columns_const(
+    ast: &syn::DeriveInput,
+    struct_attrs: &ir::GeormStructAttributes,
+    fields: &[GeormField],
+) -> proc_macro2::TokenStream {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+    let fields: Vec<GeormField> = fields.iter().filter(|f| !f.skip).cloned().collect();
+    let columns = traits::row_columns(&fields);
+    let not_deleted = traits::not_deleted(&fields).unwrap_or_else(|| String::from("TRUE"));
+    let order_by = struct_attrs
+        .order_by
+        .as_ref()
+        .map(|order_by| format!(" ORDER BY {order_by}"))
+        .unwrap_or_default();
+    quote! {
+        impl #impl_generics #struct_name #type_generics #where_clause {
+            #[doc(hidden)]
+            pub const GEORM_COLUMNS: &'static str = #columns;
+            #[doc(hidden)]
+            pub const GEORM_NOT_DELETED: &'static str = #not_deleted;
+            #[doc(hidden)]
+            pub const GEORM_ORDER_BY: &'static str = #order_by;
+        }
+    }
+}
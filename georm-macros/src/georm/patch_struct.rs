@@ -0,0 +1,190 @@
+//! This module creates the patch version of a struct derived with Georm. It
+//! creates a new struct named `<StructName>Patch` where every field that can be
+//! updated becomes an `Option<T>`, where `T` is the initial type of the field.
+//!
+//! Its `apply` method updates the row with the given primary key, only writing
+//! the columns whose field is `Some`, so that concurrent edits to other columns
+//! are preserved. Like `update`, it also sets `updated_at` timestamps and
+//! increments versions, without checking them.
+//!
+//! Fields that `update` does not write, such as primary keys, generated columns
+//! and immutable fields, are not part of `<StructName>Patch`.
+//!
+//! The patch struct is only generated for structs with the `patch` attribute.
+//! Since the columns written by `apply` depend on the fields that are set, its
+//! query is built at runtime and not checked at compile time.
+
+use super::IdType;
+use super::ir::{GeormField, GeormStructAttributes};
+use quote::quote;
+
+fn create_patch_field(field: &GeormField) -> proc_macro2::TokenStream {
+    let ident = &field.ident;
+    let ty = &field.ty;
+    let vis = &field.field.vis;
+    quote! {
+        #vis #ident: Option<#ty>
+    }
+}
+
+fn generate_apply(
+    struct_name: &syn::Ident,
+    struct_attrs: &GeormStructAttributes,
+    fields: &[GeormField],
+    patched_fields: &[&GeormField],
+    id: &IdType,
+) -> proc_macro2::TokenStream {
+    let table = &struct_attrs.table;
+    let returning = super::traits::row_columns(fields);
+
+    // Columns written on every update regardless of the patch, such as
+    // `updated_at` timestamps and versions
+    let static_columns: Vec<String> = fields
+        .iter()
+        .filter_map(|field| {
+            let column = field.column();
+            if field.version {
+                Some(format!("{column} = {column} + 1"))
+            } else if field.is_updatable() {
+                field.sql_value().map(|value| format!("{column} = {value}"))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    // Generate column checks and binds for the patched fields
+    let mut column_checks = Vec::new();
+    let mut bind_checks = Vec::new();
+
+    for field in patched_fields {
+        let column = field.column();
+        let field_ident = &field.ident;
+        let value = field.bind_value(quote! { (*value) });
+
+        column_checks.push(quote! {
+            if self.#field_ident.is_some() {
+                bound_count += 1;
+                columns.push(format!("{} = ${}", #column, bound_count));
+            }
+        });
+
+        bind_checks.push(quote! {
+            if let Some(value) = &self.#field_ident {
+                query_builder = query_builder.bind(#value);
+            }
+        });
+    }
+
+    let (id_type, id_columns, id_binds) = match id {
+        IdType::Simple {
+            field_type, column, ..
+        } => (
+            quote! { #field_type },
+            vec![column.clone()],
+            vec![quote! { id }],
+        ),
+        IdType::Composite { fields, field_type } => (
+            quote! { #field_type },
+            fields.iter().map(|field| field.column.clone()).collect(),
+            fields
+                .iter()
+                .map(|field| {
+                    let name = &field.name;
+                    quote! { &id.#name }
+                })
+                .collect(),
+        ),
+    };
+
+    quote! {
+        /// Update the entity with primary key `id`, only writing the columns
+        /// whose field is set. Nothing is written when no field is set.
+        ///
+        /// Its query depends on the fields that are set, so unlike the methods of
+        /// `Georm` it is built at runtime and not checked at compile time.
+        ///
+        /// # Errors
+        /// Returns `sqlx::Error::RowNotFound` if no entity has this primary
+        /// key, and `sqlx::Error` for other database errors.
+        pub async fn apply<'e, E>(&self, executor: E, id: &#id_type) -> ::sqlx::Result<#struct_name>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            let mut columns: Vec<String> = Vec::new();
+            let mut bound_count = 0;
+
+            #(#column_checks)*
+
+            let id_condition = [#(#id_columns),*]
+                .iter()
+                .enumerate()
+                .map(|(i, column)| format!("{} = ${}", column, bound_count + i + 1))
+                .collect::<Vec<String>>()
+                .join(" AND ");
+
+            let query = if columns.is_empty() {
+                format!("SELECT {} FROM {} WHERE {}", #returning, #table, id_condition)
+            } else {
+                columns.extend([#(String::from(#static_columns)),*]);
+                format!(
+                    "UPDATE {} SET {} WHERE {} RETURNING {}",
+                    #table,
+                    columns.join(", "),
+                    id_condition,
+                    #returning
+                )
+            };
+
+            let mut query_builder = ::sqlx::query_as::<_, #struct_name>(&query);
+
+            // Bind the patched fields first
+            #(#bind_checks)*
+
+            // Then the primary key
+            #(query_builder = query_builder.bind(#id_binds);)*
+
+            query_builder.fetch_one(executor).await
+        }
+    }
+}
+
+pub fn derive_patch_struct(
+    ast: &syn::DeriveInput,
+    struct_attrs: &GeormStructAttributes,
+    fields: &[GeormField],
+    id: &IdType,
+) -> proc_macro2::TokenStream {
+    if !struct_attrs.patch {
+        return quote! {};
+    }
+    // Skipped fields are not stored, the updated entity gets their default value
+    let fields: Vec<GeormField> = fields.iter().filter(|f| !f.skip).cloned().collect();
+    let fields = fields.as_slice();
+
+    let struct_name = &ast.ident;
+    let vis = &ast.vis;
+    let patch_struct_name = quote::format_ident!("{}Patch", struct_name);
+
+    let patched_fields: Vec<&GeormField> = fields
+        .iter()
+        .filter(|f| f.is_updatable() && f.sql_value().is_none())
+        .collect();
+    let patch_fields: Vec<proc_macro2::TokenStream> = patched_fields
+        .iter()
+        .map(|field| create_patch_field(field))
+        .collect();
+
+    let apply = generate_apply(struct_name, struct_attrs, fields, &patched_fields, id);
+
+    quote! {
+        #[derive(Default)]
+        #vis struct #patch_struct_name {
+            #(#patch_fields),*
+        }
+
+        impl #patch_struct_name {
+            #apply
+        }
+    }
+}
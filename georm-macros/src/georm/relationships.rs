@@ -32,14 +32,8 @@ pub fn derive_relationships(
     id: &IdType,
 ) -> TokenStream {
     let id = match id {
-        IdType::Simple {
-            field_name,
-            field_type: _,
-        } => field_name.to_string(),
-        IdType::Composite {
-            fields: _,
-            field_type: _,
-        } => {
+        IdType::Simple { column, .. } => column.clone(),
+        IdType::Composite { .. } => {
             eprintln!(
                 "Warning: entity {}: Relationships are not supported for entities with composite primary keys yet",
                 ast.ident
@@ -1,27 +1,61 @@
-use crate::georm::GeormField;
+use crate::georm::{GeormField, IdType};
 use quote::quote;
 
-pub fn generate_create_query(table: &str, fields: &[GeormField]) -> proc_macro2::TokenStream {
-    let inputs: Vec<String> = (1..=fields.len()).map(|num| format!("${num}")).collect();
+pub fn generate_create_query(
+    table: &str,
+    fields: &[GeormField],
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let inserted = super::inserted_fields(fields);
     let create_string = format!(
-        "INSERT INTO {table} ({}) VALUES ({}) RETURNING *",
-        fields
-            .iter()
-            .map(|f| f.ident.to_string())
-            .collect::<Vec<String>>()
-            .join(", "),
-        inputs.join(", ")
+        "INSERT INTO {table} ({}) VALUES ({}) RETURNING {}",
+        super::columns(&inserted),
+        super::values(&inserted).join(", "),
+        super::select_columns(fields)
     );
-    let field_idents: Vec<syn::Ident> = fields.iter().map(|f| f.ident.clone()).collect();
+    let args = super::query_args(&super::bound_fields(&inserted));
     quote! {
-        async fn create(&self, pool: &::sqlx::PgPool) -> ::sqlx::Result<Self> {
-            ::sqlx::query_as!(
-                Self,
-                #create_string,
-                #(self.#field_idents),*
-            )
-            .fetch_one(pool)
+        async fn create<'e, E>(&self, executor: E) -> ::sqlx::Result<Self>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            ::sqlx::query!(#create_string, #(#args),*)
+                .try_map(#from_record)
+            .fetch_one(executor)
             .await
         }
     }
 }
+
+pub fn generate_create_many_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (insert, bound) = super::insert_from_unnest(table, &super::inserted_fields(fields), "");
+    let create_many_string = super::returning_in_input_order(&insert, fields, &bound, id);
+    let batch_size = super::BATCH_SIZE;
+    let binds = super::batch_binds(&bound);
+    quote! {
+        async fn create_many<'a, A>(entities: &[Self], acquire: A) -> ::sqlx::Result<Vec<Self>>
+        where
+            A: ::sqlx::Acquire<'a, Database = ::sqlx::Postgres> + Send,
+        {
+            if entities.is_empty() {
+                return Ok(Vec::new());
+            }
+            let mut tx = acquire.begin().await?;
+            let mut created = Vec::with_capacity(entities.len());
+            for batch in entities.chunks(#batch_size) {
+                let rows = ::sqlx::query!(#create_many_string, #(#binds),*)
+                    .try_map(#from_record)
+                    .fetch_all(&mut *tx)
+                    .await?;
+                created.extend(rows);
+            }
+            tx.commit().await?;
+            Ok(created)
+        }
+    }
+}
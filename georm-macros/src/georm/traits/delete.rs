@@ -1,39 +1,197 @@
-use crate::georm::IdType;
+use crate::georm::{GeormField, IdType};
 use quote::quote;
 
-pub fn generate_delete_query(table: &str, id: &IdType) -> proc_macro2::TokenStream {
+pub fn generate_delete_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+) -> proc_macro2::TokenStream {
     let where_clause = match id {
-        IdType::Simple { field_name, .. } => format!("{} = $1", field_name),
+        IdType::Simple { column, .. } => format!("{column} = $1"),
         IdType::Composite { fields, .. } => fields
             .iter()
             .enumerate()
-            .map(|(i, field)| format!("{} = ${}", field.name, i + 1))
+            .map(|(i, field)| format!("{} = ${}", field.column, i + 1))
             .collect::<Vec<String>>()
             .join(" AND "),
     };
     let query_args = match id {
-        IdType::Simple { .. } => quote! { id },
+        IdType::Simple { field_type, .. } => quote! { id as &#field_type },
         IdType::Composite { fields, .. } => {
-            let fields: Vec<syn::Ident> = fields.iter().map(|f| f.name.clone()).collect();
-            quote! { #(id.#fields), * }
+            let args = fields.iter().map(|field| field.query_arg(quote! { id }));
+            quote! { #(#args), * }
         }
     };
     let id_type = match id {
         IdType::Simple { field_type, .. } => quote! { #field_type },
         IdType::Composite { field_type, .. } => quote! { #field_type },
     };
-    let delete_string = format!("DELETE FROM {table} WHERE {where_clause}");
+    let delete_string = format!(
+        "{} WHERE {where_clause}{}",
+        super::delete_statement(table, fields),
+        super::and_not_deleted(fields)
+    );
     quote! {
-        async fn delete_by_id(pool: &::sqlx::PgPool, id: &#id_type) -> ::sqlx::Result<u64> {
+        async fn delete_by_id<'e, E>(executor: E, id: &#id_type) -> ::sqlx::Result<u64>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
             let rows_affected = ::sqlx::query!(#delete_string, #query_args)
-                .execute(pool)
+                .execute(executor)
                 .await?
                 .rows_affected();
             Ok(rows_affected)
         }
 
-        async fn delete(&self, pool: &::sqlx::PgPool) -> ::sqlx::Result<u64> {
-            Self::delete_by_id(pool, &self.get_id()).await
+        async fn delete<'e, E>(&self, executor: E) -> ::sqlx::Result<u64>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            Self::delete_by_id(executor, &self.get_id()).await
+        }
+    }
+}
+
+pub fn generate_delete_many_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+) -> proc_macro2::TokenStream {
+    let delete = super::delete_statement(table, fields);
+    let and_not_deleted = super::and_not_deleted(fields);
+    match id {
+        IdType::Simple {
+            field_type, column, ..
+        } => {
+            let delete_string = format!("{delete} WHERE {column} = ANY($1){and_not_deleted}");
+            quote! {
+                async fn delete_many_by_ids<'e, E>(executor: E, ids: &[#field_type]) -> ::sqlx::Result<u64>
+                where
+                    E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+                {
+                    let rows_affected = ::sqlx::query!(#delete_string, ids as &[#field_type])
+                        .execute(executor)
+                        .await?
+                        .rows_affected();
+                    Ok(rows_affected)
+                }
+            }
+        }
+        IdType::Composite {
+            fields: id_fields,
+            field_type,
+        } => {
+            let (unnest, id_args) = super::unnest_ids(table, fields);
+            let delete_string = format!(
+                "{delete} WHERE ({}) IN (SELECT * FROM {unnest}){and_not_deleted}",
+                super::id_columns(id_fields),
+            );
+            quote! {
+                async fn delete_many_by_ids<'e, E>(executor: E, ids: &[#field_type]) -> ::sqlx::Result<u64>
+                where
+                    E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+                {
+                    let rows_affected = ::sqlx::query!(#delete_string, #(#id_args),*)
+                        .execute(executor)
+                        .await?
+                        .rows_affected();
+                    Ok(rows_affected)
+                }
+            }
+        }
+    }
+}
+
+pub fn generate_delete_versioned_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+    version: &GeormField,
+) -> proc_macro2::TokenStream {
+    let (id_match_string, mut args) = super::self_id_condition(id, 0);
+    let delete_string = format!(
+        "{} WHERE {id_match_string} AND {} = ${}{}",
+        super::delete_statement(table, fields),
+        version.column(),
+        args.len() + 1,
+        super::and_not_deleted(fields)
+    );
+    let version_ident = &version.ident;
+    args.push(version.query_arg(quote! { self.#version_ident }));
+    quote! {
+        /// Delete this entity like `delete`, but only if its row still has this
+        /// entity's version.
+        ///
+        /// # Errors
+        /// Returns a [`georm::VersionConflict`] if no row has this entity's primary
+        /// key and version, and `sqlx::Error` for other database errors.
+        pub async fn delete_versioned<'e, E>(&self, executor: E) -> ::sqlx::Result<u64>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            let rows_affected = ::sqlx::query!(#delete_string, #(#args),*)
+                .execute(executor)
+                .await?
+                .rows_affected();
+            if rows_affected == 0 {
+                return Err(::georm::VersionConflict::new(#table).into());
+            }
+            Ok(rows_affected)
+        }
+    }
+}
+
+pub fn generate_hard_delete_query(table: &str, id: &IdType) -> proc_macro2::TokenStream {
+    let (id_match_string, args) = super::self_id_condition(id, 0);
+    let delete_string = format!("DELETE FROM {table} WHERE {id_match_string}");
+    quote! {
+        /// Permanently delete this entity's row, whether it is soft-deleted or not.
+        ///
+        /// # Errors
+        /// Returns `sqlx::Error` for database errors.
+        pub async fn hard_delete<'e, E>(&self, executor: E) -> ::sqlx::Result<u64>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            let rows_affected = ::sqlx::query!(#delete_string, #(#args),*)
+                .execute(executor)
+                .await?
+                .rows_affected();
+            Ok(rows_affected)
+        }
+    }
+}
+
+pub fn generate_restore_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+    soft_delete: &GeormField,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (id_match_string, args) = super::self_id_condition(id, 0);
+    let restore_string = format!(
+        "UPDATE {table} SET {} = NULL WHERE {id_match_string} RETURNING {}",
+        soft_delete.column(),
+        super::select_columns(fields)
+    );
+    quote! {
+        /// Restore this entity's soft-deleted row.
+        ///
+        /// # Returns
+        /// The entity as it exists in the database after being restored.
+        ///
+        /// # Errors
+        /// Returns `sqlx::Error` if no row has this entity's primary key, and for
+        /// other database errors.
+        pub async fn restore<'e, E>(&self, executor: E) -> ::sqlx::Result<Self>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            ::sqlx::query!(#restore_string, #(#args),*)
+                .try_map(#from_record)
+                .fetch_one(executor)
+                .await
         }
     }
 }
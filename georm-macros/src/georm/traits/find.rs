@@ -1,26 +1,313 @@
-use crate::georm::IdType;
+use crate::georm::{GeormField, IdType};
 use quote::quote;
 
-pub fn generate_find_all_query(table: &str) -> proc_macro2::TokenStream {
-    let find_string = format!("SELECT * FROM {table}");
+/// `WHERE` clause leaving out soft-deleted rows, empty if the entity has no soft
+/// delete field.
+fn where_not_deleted(fields: &[GeormField]) -> String {
+    super::not_deleted(fields)
+        .map(|condition| format!(" WHERE {condition}"))
+        .unwrap_or_default()
+}
+
+/// Query selecting the whole table, in the struct's `order_by` order if any.
+fn select_all(table: &str, fields: &[GeormField], order_by: Option<&str>) -> String {
+    let columns = super::select_columns(fields);
+    let where_clause = where_not_deleted(fields);
+    match order_by {
+        Some(order_by) => {
+            format!("SELECT {columns} FROM {table}{where_clause} ORDER BY {order_by}")
+        }
+        None => format!("SELECT {columns} FROM {table}{where_clause}"),
+    }
+}
+
+pub fn generate_find_all_query(
+    table: &str,
+    fields: &[GeormField],
+    order_by: Option<&str>,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let find_string = select_all(table, fields, order_by);
     quote! {
-        async fn find_all(pool: &::sqlx::PgPool) -> ::sqlx::Result<Vec<Self>> {
-            ::sqlx::query_as!(Self, #find_string).fetch_all(pool).await
+        async fn find_all<'e, E>(executor: E) -> ::sqlx::Result<Vec<Self>>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            ::sqlx::query!(#find_string)
+                .try_map(#from_record)
+                .fetch_all(executor)
+                .await
         }
     }
 }
 
-pub fn generate_find_query(table: &str, id: &IdType) -> proc_macro2::TokenStream {
+pub fn generate_find_all_stream_query(
+    table: &str,
+    fields: &[GeormField],
+    order_by: Option<&str>,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let find_string = select_all(table, fields, order_by);
+    quote! {
+        fn find_all_stream<'e, E>(
+            executor: E,
+        ) -> impl ::georm::futures_core::Stream<Item = ::sqlx::Result<Self>> + Send + 'e
+        where
+            Self: 'e,
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres> + 'e,
+        {
+            ::sqlx::query!(#find_string)
+                .try_map(#from_record)
+                .fetch(executor)
+        }
+    }
+}
+
+pub fn generate_find_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let filter = super::and_not_deleted(fields);
+    find_by_id(
+        quote! { async fn find },
+        table,
+        fields,
+        id,
+        from_record,
+        &filter,
+    )
+}
+
+pub fn generate_find_with_deleted_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let function = quote! {
+        /// Find an entity by primary key like `find`, including soft-deleted ones.
+        ///
+        /// # Errors
+        /// Returns `sqlx::Error` for database errors.
+        pub async fn find_with_deleted
+    };
+    find_by_id(function, table, fields, id, from_record, "")
+}
+
+/// Function named by `function` finding an entity by primary key, among the rows
+/// matching the additional `filter` conditions.
+fn find_by_id(
+    function: proc_macro2::TokenStream,
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+    from_record: &proc_macro2::TokenStream,
+    filter: &str,
+) -> proc_macro2::TokenStream {
+    let columns = super::select_columns(fields);
     match id {
         IdType::Simple {
-            field_name,
+            field_type, column, ..
+        } => {
+            let find_string = format!("SELECT {columns} FROM {table} WHERE {column} = $1{filter}");
+            quote! {
+                #function<'e, E>(executor: E, id: &#field_type) -> ::sqlx::Result<Option<Self>>
+                where
+                    E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+                {
+                    ::sqlx::query!(#find_string, id as &#field_type)
+                    .try_map(#from_record)
+                    .fetch_optional(executor)
+                    .await
+                }
+            }
+        }
+        IdType::Composite { fields, field_type } => {
+            let id_match_string = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| format!("{} = ${}", field.column, i + 1))
+                .collect::<Vec<String>>()
+                .join(" AND ");
+            let id_args = fields.iter().map(|field| field.query_arg(quote! { id }));
+            let find_string =
+                format!("SELECT {columns} FROM {table} WHERE {id_match_string}{filter}");
+            quote! {
+                #function<'e, E>(executor: E, id: &#field_type) -> ::sqlx::Result<Option<Self>>
+                where
+                    E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+                {
+                    ::sqlx::query!(#find_string, #(#id_args),*)
+                    .try_map(#from_record)
+                    .fetch_optional(executor)
+                    .await
+                }
+            }
+        }
+    }
+}
+
+pub fn generate_find_many_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let filter = super::and_not_deleted(fields);
+    match id {
+        IdType::Simple {
+            field_type, column, ..
+        } => {
+            let find_string = format!(
+                "SELECT {} FROM {table} WHERE {column} = ANY($1){filter}",
+                super::select_columns(fields)
+            );
+            quote! {
+                async fn find_many<'e, E>(executor: E, ids: &[#field_type]) -> ::sqlx::Result<Vec<Self>>
+                where
+                    E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+                {
+                    ::sqlx::query!(#find_string, ids as &[#field_type])
+                    .try_map(#from_record)
+                    .fetch_all(executor)
+                    .await
+                }
+            }
+        }
+        IdType::Composite {
+            fields: id_fields,
             field_type,
         } => {
-            let find_string = format!("SELECT * FROM {table} WHERE {} = $1", field_name);
+            let (unnest, id_args) = super::unnest_ids(table, fields);
+            let find_string = format!(
+                "SELECT {} FROM {table} WHERE ({}) IN (SELECT * FROM {unnest}){filter}",
+                super::select_columns(fields),
+                super::id_columns(id_fields),
+            );
+            quote! {
+                async fn find_many<'e, E>(executor: E, ids: &[#field_type]) -> ::sqlx::Result<Vec<Self>>
+                where
+                    E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+                {
+                    ::sqlx::query!(#find_string, #(#id_args),*)
+                    .try_map(#from_record)
+                    .fetch_all(executor)
+                    .await
+                }
+            }
+        }
+    }
+}
+
+pub fn generate_find_page_query(
+    table: &str,
+    fields: &[GeormField],
+    entity_init: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let key_fields: Vec<&GeormField> = fields.iter().filter(|field| field.id).collect();
+    let order_by = key_fields
+        .iter()
+        .map(|field| field.column())
+        .collect::<Vec<String>>()
+        .join(", ");
+    let cursor_object = key_fields
+        .iter()
+        .map(|field| format!("'{}', {}", field.column_name(), field.column()))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let cursor_columns = key_fields
+        .iter()
+        .map(|field| format!("after_key.{}", field.column()))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let select = format!(
+        "SELECT {}, jsonb_build_object({cursor_object})::text AS \"georm_cursor!\" FROM {table}",
+        super::select_columns(fields)
+    );
+    let first_page_string = format!(
+        "{select}{} ORDER BY {order_by} LIMIT $1",
+        where_not_deleted(fields)
+    );
+    let next_page_string = format!(
+        "{select} WHERE ({order_by}) > (SELECT {cursor_columns} FROM jsonb_populate_record(NULL::{table}, $1::text::jsonb) AS after_key){} ORDER BY {order_by} LIMIT $2",
+        super::and_not_deleted(fields)
+    );
+    // Each entity is fetched along with its cursor, the one of the last entity of
+    // the page being handed out
+    let with_cursor = quote! {
+        |record| -> ::sqlx::Result<(Self, String)> {
+            let cursor = record.georm_cursor;
+            Ok((#entity_init, cursor))
+        }
+    };
+    quote! {
+        async fn find_page<'e, E>(
+            executor: E,
+            request: ::georm::PageRequest,
+        ) -> ::sqlx::Result<::georm::Page<Self>>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            let limit = request.limit.max(1);
+            let rows = match &request.after {
+                Some(cursor) => {
+                    if !cursor.is_for(#table) {
+                        return Err(::georm::InvalidCursor.into());
+                    }
+                    ::sqlx::query!(#next_page_string, cursor.as_json(), i64::from(limit) + 1)
+                        .try_map(#with_cursor)
+                        .fetch_all(executor)
+                        .await?
+                }
+                None => {
+                    ::sqlx::query!(#first_page_string, i64::from(limit) + 1)
+                        .try_map(#with_cursor)
+                        .fetch_all(executor)
+                        .await?
+                }
+            };
+            Ok(::georm::Page::from_items(rows, limit, #table))
+        }
+    }
+}
+
+pub fn generate_count_query(table: &str, fields: &[GeormField]) -> proc_macro2::TokenStream {
+    let count_string = format!(
+        "SELECT COUNT(*) AS \"count!\" FROM {table}{}",
+        where_not_deleted(fields)
+    );
+    quote! {
+        async fn count<'e, E>(executor: E) -> ::sqlx::Result<i64>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            ::sqlx::query_scalar!(#count_string).fetch_one(executor).await
+        }
+    }
+}
+
+pub fn generate_exists_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+) -> proc_macro2::TokenStream {
+    let filter = super::and_not_deleted(fields);
+    match id {
+        IdType::Simple {
+            field_type, column, ..
+        } => {
+            let exists_string = format!(
+                "SELECT EXISTS(SELECT 1 FROM {table} WHERE {column} = $1{filter}) AS \"exists!\""
+            );
             quote! {
-                async fn find(pool: &::sqlx::PgPool, id: &#field_type) -> ::sqlx::Result<Option<Self>> {
-                    ::sqlx::query_as!(Self, #find_string, id)
-                    .fetch_optional(pool)
+                async fn exists<'e, E>(executor: E, id: &#field_type) -> ::sqlx::Result<bool>
+                where
+                    E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+                {
+                    ::sqlx::query_scalar!(#exists_string, id as &#field_type)
+                    .fetch_one(executor)
                     .await
                 }
             }
@@ -29,16 +316,20 @@ pub fn generate_find_query(table: &str, id: &IdType) -> proc_macro2::TokenStream
             let id_match_string = fields
                 .iter()
                 .enumerate()
-                .map(|(i, field)| format!("{} = ${}", field.name, i + 1))
+                .map(|(i, field)| format!("{} = ${}", field.column, i + 1))
                 .collect::<Vec<String>>()
                 .join(" AND ");
-            let id_members: Vec<syn::Ident> =
-                fields.iter().map(|field| field.name.clone()).collect();
-            let find_string = format!("SELECT * FROM {table} WHERE {id_match_string}");
+            let id_args = fields.iter().map(|field| field.query_arg(quote! { id }));
+            let exists_string = format!(
+                "SELECT EXISTS(SELECT 1 FROM {table} WHERE {id_match_string}{filter}) AS \"exists!\""
+            );
             quote! {
-                async fn find(pool: &::sqlx::PgPool, id: &#field_type) -> ::sqlx::Result<Option<Self>> {
-                    ::sqlx::query_as!(Self, #find_string, #(id.#id_members),*)
-                    .fetch_optional(pool)
+                async fn exists<'e, E>(executor: E, id: &#field_type) -> ::sqlx::Result<bool>
+                where
+                    E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+                {
+                    ::sqlx::query_scalar!(#exists_string, #(#id_args),*)
+                    .fetch_one(executor)
                     .await
                 }
             }
@@ -1,5 +1,5 @@
-use super::composite_keys::IdType;
-use super::ir::GeormField;
+use super::composite_keys::{IdField, IdType};
+use super::ir::{GeormField, GeormStructAttributes};
 use quote::quote;
 
 mod create;
@@ -8,11 +8,357 @@ mod find;
 mod update;
 mod upsert;
 
+/// Rows written by each statement of bulk operations. Since every column is bound
+/// as a single array, the number of parameters does not depend on the batch
+/// size, but each array is limited to 1 GB by PostgreSQL and every row of a batch
+/// is held in memory at once, so large slices are still split.
+const BATCH_SIZE: usize = 10_000;
+
+/// Comma-separated list of the columns making up a composite key.
+fn id_columns(fields: &[IdField]) -> String {
+    fields
+        .iter()
+        .map(|field| field.column.clone())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Comma-separated list of the columns of `fields`, as SQL identifiers.
+fn columns(fields: &[GeormField]) -> String {
+    fields
+        .iter()
+        .map(GeormField::column)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Select list mapping every column to its field, for `query!` queries.
+pub fn select_columns(fields: &[GeormField]) -> String {
+    fields
+        .iter()
+        .map(GeormField::select_column)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Field holding the version of the entity, if any.
+fn version_field(fields: &[GeormField]) -> Option<&GeormField> {
+    fields.iter().find(|f| f.version)
+}
+
+/// Condition leaving out soft-deleted rows, if the entity has a soft delete field.
+pub fn not_deleted(fields: &[GeormField]) -> Option<String> {
+    fields
+        .iter()
+        .find(|f| f.soft_delete)
+        .map(|f| format!("{} IS NULL", f.column()))
+}
+
+/// [`not_deleted`] condition as an additional `AND` clause, empty if the entity
+/// has no soft delete field.
+fn and_not_deleted(fields: &[GeormField]) -> String {
+    not_deleted(fields)
+        .map(|condition| format!(" AND {condition}"))
+        .unwrap_or_default()
+}
+
+/// Statement deleting rows, completed by a `WHERE` clause. Rows of entities with
+/// a soft delete field are marked as deleted rather than removed.
+fn delete_statement(table: &str, fields: &[GeormField]) -> String {
+    match fields.iter().find(|f| f.soft_delete) {
+        Some(field) => format!("UPDATE {table} SET {} = now()", field.column()),
+        None => format!("DELETE FROM {table}"),
+    }
+}
+
+/// Condition matching the primary key of `self`, with parameters starting after
+/// `offset`, along with its `query!` arguments.
+fn self_id_condition(id: &IdType, offset: usize) -> (String, Vec<proc_macro2::TokenStream>) {
+    match id {
+        IdType::Simple {
+            field_name,
+            field_type,
+            column,
+        } => (
+            format!("{column} = ${}", offset + 1),
+            vec![quote! { &self.#field_name as &#field_type }],
+        ),
+        IdType::Composite { fields, .. } => (
+            fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| format!("{} = ${}", field.column, offset + i + 1))
+                .collect::<Vec<String>>()
+                .join(" AND "),
+            fields
+                .iter()
+                .map(|field| field.query_arg(quote! { self }))
+                .collect(),
+        ),
+    }
+}
+
+/// Fields written by `create`, leaving out columns generated by the database.
+fn inserted_fields(fields: &[GeormField]) -> Vec<GeormField> {
+    fields.iter().filter(|f| !f.generated).cloned().collect()
+}
+
+/// Fields written by upserts. Generated primary keys are written anyway so that
+/// conflicts can be detected, which requires `OVERRIDING SYSTEM VALUE`.
+fn upserted_fields(fields: &[GeormField]) -> (Vec<GeormField>, &'static str) {
+    let upserted = fields
+        .iter()
+        .filter(|f| f.id || !f.generated)
+        .cloned()
+        .collect();
+    let overriding = if fields.iter().any(|f| f.id && f.generated) {
+        " OVERRIDING SYSTEM VALUE"
+    } else {
+        ""
+    };
+    (upserted, overriding)
+}
+
+/// `DO UPDATE SET` assignments of the fields that can be updated, bumping the
+/// version if there is one. Without any, the first column of the conflict
+/// `target` is assigned to itself, since `DO UPDATE` needs at least one
+/// assignment to return the existing row.
+pub fn upsert_assignments(table: &str, upserted: &[GeormField], target: &str) -> String {
+    let assignments = upserted
+        .iter()
+        .filter(|f| f.is_updatable())
+        .map(|f| format!("{} = EXCLUDED.{}", f.column(), f.column()))
+        .chain(
+            version_field(upserted).map(|f| format!("{} = {table}.{} + 1", f.column(), f.column())),
+        )
+        .collect::<Vec<String>>()
+        .join(", ");
+    if assignments.is_empty() {
+        let column = target.split(',').next().unwrap_or(target).trim();
+        format!("{column} = EXCLUDED.{column}")
+    } else {
+        assignments
+    }
+}
+
+/// Fields whose value is bound as a parameter, leaving out the ones written by
+/// the database such as timestamps.
+pub fn bound_fields(fields: &[GeormField]) -> Vec<GeormField> {
+    fields
+        .iter()
+        .filter(|f| f.sql_value().is_none())
+        .cloned()
+        .collect()
+}
+
+/// `VALUES` items writing `fields`: their SQL expression if they have one,
+/// positional parameters starting at `$1` otherwise.
+pub fn values(fields: &[GeormField]) -> Vec<String> {
+    let mut bound = 0;
+    fields
+        .iter()
+        .map(|field| match field.sql_value() {
+            Some(value) => value.to_string(),
+            None => {
+                bound += 1;
+                format!("${bound}")
+            }
+        })
+        .collect()
+}
+
+/// Statement inserting `fields` from arrays bound as `UNNEST` parameters, along
+/// with the fields bound, in parameter order. Rows are inserted in input order,
+/// so that values generated by the database such as serial keys follow it.
+fn insert_from_unnest(
+    table: &str,
+    fields: &[GeormField],
+    overriding: &str,
+) -> (String, Vec<GeormField>) {
+    let bound = bound_fields(fields);
+    let written: Vec<GeormField> = fields
+        .iter()
+        .filter(|f| f.sql_value().is_some())
+        .cloned()
+        .collect();
+    let inputs: Vec<String> = (1..=bound.len())
+        .map(|i| format!("georm_input_{i}"))
+        .collect();
+    let select = inputs
+        .iter()
+        .cloned()
+        .chain(
+            written
+                .iter()
+                .filter_map(GeormField::sql_value)
+                .map(String::from),
+        )
+        .collect::<Vec<String>>()
+        .join(", ");
+    let arrays = bound
+        .iter()
+        .enumerate()
+        .map(|(i, field)| field.array_param(i + 1, table))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let statement = format!(
+        "INSERT INTO {table} ({}){overriding} SELECT {select} FROM UNNEST({arrays}) WITH ORDINALITY AS input({}, georm_ordinality) ORDER BY georm_ordinality",
+        columns(&[bound.as_slice(), written.as_slice()].concat()),
+        inputs.join(", "),
+    );
+    (statement, bound)
+}
+
+/// Query running `statement`, built by [`insert_from_unnest`] from the `bound`
+/// fields, and returning the rows it writes in input order. PostgreSQL does not
+/// guarantee the order of `RETURNING`, so the id parameters are unnested again
+/// WITH ORDINALITY and joined to the written rows. Keys generated by the database
+/// cannot be matched to their input, such rows come back in insertion order.
+fn returning_in_input_order(
+    statement: &str,
+    fields: &[GeormField],
+    bound: &[GeormField],
+    id: &IdType,
+) -> String {
+    let id_columns: Vec<String> = match id {
+        IdType::Simple { column, .. } => vec![column.clone()],
+        IdType::Composite { fields, .. } => fields.iter().map(|f| f.column.clone()).collect(),
+    };
+    let id_inputs: Vec<String> = bound
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| f.id)
+        .map(|(index, _)| format!("${}", index + 1))
+        .collect();
+    if id_inputs.len() < id_columns.len() {
+        return format!("{statement} RETURNING {}", select_columns(fields));
+    }
+    // Inputs get their own names, so that the selected columns are not ambiguous
+    let id_aliases: Vec<String> = (1..=id_columns.len())
+        .map(|i| format!("georm_key_{i}"))
+        .collect();
+    let join_condition = id_columns
+        .iter()
+        .zip(&id_aliases)
+        .map(|(column, alias)| format!("written.{column} = input.{alias}"))
+        .collect::<Vec<String>>()
+        .join(" AND ");
+    format!(
+        "WITH written AS ({statement} RETURNING {}) \
+         SELECT {} FROM written JOIN UNNEST({}) WITH ORDINALITY AS input({}, georm_ordinality) ON {join_condition} ORDER BY input.georm_ordinality",
+        columns(fields),
+        select_columns(fields),
+        id_inputs.join(", "),
+        id_aliases.join(", "),
+    )
+}
+
+/// Select list of the columns read by `FromRow`, for runtime queries.
+pub fn row_columns(fields: &[GeormField]) -> String {
+    fields
+        .iter()
+        .map(GeormField::row_column)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// `UNNEST` of one array per member of the composite keys `ids`, along with its
+/// `query!` arguments. Arrays of custom types have no built-in mapping, so their
+/// types are overridden with vectors of the fields' types. SQLx does not check
+/// overridden parameters against the statement, which instead casts each array
+/// to the type of its column.
+fn unnest_ids(table: &str, fields: &[GeormField]) -> (String, Vec<proc_macro2::TokenStream>) {
+    let id_fields: Vec<&GeormField> = fields.iter().filter(|f| f.id).collect();
+    let arrays = id_fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| field.array_param(i + 1, table))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let args = id_fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let ty = &field.ty;
+            quote! { ids.iter().map(|id| &id.#ident).collect::<Vec<_>>() as Vec<&#ty> }
+        })
+        .collect();
+    (format!("UNNEST({arrays})"), args)
+}
+
+/// `query!` arguments passing the value of each field of `self`.
+fn query_args(fields: &[GeormField]) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            field.query_arg(quote! { self.#ident })
+        })
+        .collect()
+}
+
+/// Arrays of the values of each field across a `batch` of entities, to be
+/// bound as `UNNEST` parameters. Like in `unnest_ids`, their types are
+/// overridden with vectors of the fields' types, and the statement casts them to
+/// the types of their columns.
+fn batch_binds(fields: &[GeormField]) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let value = field.bind_value(quote! { entity.#ident });
+            let bind_type = field.bind_type();
+            quote! { batch.iter().map(|entity| #value).collect::<Vec<_>>() as Vec<#bind_type> }
+        })
+        .collect()
+}
+
+/// Closure building `Self` from a `query!` record, skipped fields being filled
+/// with their default value. Meant for `try_map`, as JSON fields may fail to decode.
+fn generate_from_record(fields: &[GeormField]) -> proc_macro2::TokenStream {
+    entity_from_record(&quote! { Self }, fields)
+}
+
+/// Closure building the `entity` type from a `query!` record, like
+/// `generate_from_record`.
+pub fn entity_from_record(
+    entity: &proc_macro2::TokenStream,
+    fields: &[GeormField],
+) -> proc_macro2::TokenStream {
+    let entity_init = record_to_entity(entity, fields);
+    quote! {
+        |record| -> ::sqlx::Result<#entity> { Ok(#entity_init) }
+    }
+}
+
+/// Expression building the `entity` type from a `query!` record bound to
+/// `record`, skipped fields being filled with their default value.
+fn record_to_entity(
+    entity: &proc_macro2::TokenStream,
+    fields: &[GeormField],
+) -> proc_macro2::TokenStream {
+    let field_inits: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            if field.skip {
+                let default = field.default_value();
+                quote! { #ident: #default }
+            } else {
+                let value = field.record_value();
+                quote! { #ident: #value }
+            }
+        })
+        .collect();
+    quote! { #entity { #(#field_inits),* } }
+}
+
 fn generate_get_id(id: &IdType) -> proc_macro2::TokenStream {
     match id {
         IdType::Simple {
             field_name,
             field_type,
+            ..
         } => {
             quote! {
                 fn get_id(&self) -> #field_type {
@@ -35,10 +381,12 @@ fn generate_get_id(id: &IdType) -> proc_macro2::TokenStream {
 
 pub fn derive_trait(
     ast: &syn::DeriveInput,
-    table: &str,
+    struct_attrs: &GeormStructAttributes,
     fields: &[GeormField],
     id: &IdType,
 ) -> proc_macro2::TokenStream {
+    let table = &struct_attrs.table;
+    let order_by = struct_attrs.order_by.as_deref();
     let ty = match id {
         IdType::Simple { field_type, .. } => quote! {#field_type},
         IdType::Composite { field_type, .. } => quote! {#field_type},
@@ -48,23 +396,101 @@ pub fn derive_trait(
     let ident = &ast.ident;
     let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
 
+    // Skipped fields have no column, they only appear when building `Self`
+    let from_record = generate_from_record(fields);
+    let entity_init = record_to_entity(&quote! { Self }, fields);
+    let fields: Vec<GeormField> = fields.iter().filter(|f| !f.skip).cloned().collect();
+    let fields = fields.as_slice();
+
     // generate
     let get_id = generate_get_id(id);
-    let get_all = find::generate_find_all_query(table);
-    let find_query = find::generate_find_query(table, id);
-    let create_query = create::generate_create_query(table, fields);
-    let update_query = update::generate_update_query(table, fields, id);
-    let upsert_query = upsert::generate_upsert_query(table, fields, id);
-    let delete_query = delete::generate_delete_query(table, id);
+    let get_all = find::generate_find_all_query(table, fields, order_by, &from_record);
+    let get_all_stream =
+        find::generate_find_all_stream_query(table, fields, order_by, &from_record);
+    let find_query = find::generate_find_query(table, fields, id, &from_record);
+    let find_many_query = find::generate_find_many_query(table, fields, id, &from_record);
+    let find_page_query = find::generate_find_page_query(table, fields, &entity_init);
+    let count_query = find::generate_count_query(table, fields);
+    let exists_query = find::generate_exists_query(table, fields, id);
+    let create_query = create::generate_create_query(table, fields, &from_record);
+    let create_many_query = create::generate_create_many_query(table, fields, id, &from_record);
+    let update_query = update::generate_update_query(table, fields, id, &from_record);
+    let upsert_query = upsert::generate_upsert_query(table, fields, id, &from_record);
+    let upsert_many_query = upsert::generate_upsert_many_query(table, fields, id, &from_record);
+    let delete_query = delete::generate_delete_query(table, fields, id);
+    let delete_many_query = delete::generate_delete_many_query(table, fields, id);
     quote! {
         impl #impl_generics Georm<#ty> for #ident #type_generics #where_clause {
             #get_all
+            #get_all_stream
             #get_id
             #find_query
+            #find_many_query
+            #find_page_query
+            #count_query
+            #exists_query
             #create_query
+            #create_many_query
             #update_query
             #upsert_query
+            #upsert_many_query
             #delete_query
+            #delete_many_query
+        }
+    }
+}
+
+/// Methods checking the version of entities with a `#[georm(version)]` field.
+pub fn derive_versioned_methods(
+    ast: &syn::DeriveInput,
+    struct_attrs: &GeormStructAttributes,
+    fields: &[GeormField],
+    id: &IdType,
+) -> proc_macro2::TokenStream {
+    let Some(version) = version_field(fields) else {
+        return quote! {};
+    };
+    let table = &struct_attrs.table;
+    let ident = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+    let from_record = generate_from_record(fields);
+    let fields: Vec<GeormField> = fields.iter().filter(|f| !f.skip).cloned().collect();
+    let upsert_versioned =
+        upsert::generate_upsert_versioned_query(table, &fields, id, &from_record);
+    let delete_versioned = delete::generate_delete_versioned_query(table, &fields, id, version);
+    quote! {
+        impl #impl_generics #ident #type_generics #where_clause {
+            #upsert_versioned
+            #delete_versioned
+        }
+    }
+}
+
+/// Escape hatches of entities with a `#[georm(soft_delete)]` field, reaching
+/// soft-deleted rows.
+pub fn derive_soft_delete_methods(
+    ast: &syn::DeriveInput,
+    struct_attrs: &GeormStructAttributes,
+    fields: &[GeormField],
+    id: &IdType,
+) -> proc_macro2::TokenStream {
+    let Some(soft_delete) = fields.iter().find(|f| f.soft_delete) else {
+        return quote! {};
+    };
+    let table = &struct_attrs.table;
+    let ident = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+    let from_record = generate_from_record(fields);
+    let fields: Vec<GeormField> = fields.iter().filter(|f| !f.skip).cloned().collect();
+    let find_with_deleted =
+        find::generate_find_with_deleted_query(table, &fields, id, &from_record);
+    let restore = delete::generate_restore_query(table, &fields, id, soft_delete, &from_record);
+    let hard_delete = delete::generate_hard_delete_query(table, id);
+    quote! {
+        impl #impl_generics #ident #type_generics #where_clause {
+            #find_with_deleted
+            #restore
+            #hard_delete
         }
     }
 }
@@ -5,44 +5,60 @@ pub fn generate_update_query(
     table: &str,
     fields: &[GeormField],
     id: &IdType,
+    from_record: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    let non_id_fields: Vec<syn::Ident> = fields
+    let updated: Vec<GeormField> = fields
         .iter()
-        .filter_map(|f| if f.id { None } else { Some(f.ident.clone()) })
+        .filter(|f| f.is_updatable())
+        .cloned()
         .collect();
-    let update_columns = non_id_fields
+    let version = super::version_field(fields);
+    let update_columns = updated
         .iter()
-        .enumerate()
-        .map(|(i, field)| format!("{} = ${}", field, i + 1))
+        .zip(super::values(&updated))
+        .map(|(field, value)| format!("{} = {value}", field.column()))
+        .chain(version.map(|f| format!("{} = {} + 1", f.column(), f.column())))
         .collect::<Vec<String>>()
         .join(", ");
-    let mut all_fields = non_id_fields.clone();
-    let where_clause = match id {
-        IdType::Simple { field_name, .. } => {
-            let where_clause = format!("{} = ${}", field_name, non_id_fields.len() + 1);
-            all_fields.push(field_name.clone());
-            where_clause
+    let bound = super::bound_fields(&updated);
+    let mut args = super::query_args(&bound);
+    let (where_clause, id_args) = super::self_id_condition(id, bound.len());
+    args.extend(id_args);
+    // A versioned row is only updated if it still has the entity's version
+    let (where_clause, fetch) = match version {
+        Some(version) => {
+            let ident = &version.ident;
+            args.push(version.query_arg(quote! { self.#ident }));
+            let where_clause = format!("{where_clause} AND {} = ${}", version.column(), args.len());
+            let fetch = quote! {
+                .fetch_optional(executor)
+                .await?
+                .ok_or_else(|| ::georm::VersionConflict::new(#table).into())
+            };
+            (where_clause, fetch)
         }
-        IdType::Composite { fields, .. } => fields
-            .iter()
-            .enumerate()
-            .map(|(i, field)| {
-                let where_clause = format!("{} = ${}", field.name, non_id_fields.len() + i + 1);
-                all_fields.push(field.name.clone());
-                where_clause
-            })
-            .collect::<Vec<String>>()
-            .join(" AND "),
+        None => (where_clause, quote! { .fetch_one(executor).await }),
+    };
+    // Without any column to write, the row is read as it is
+    let update_string = if update_columns.is_empty() {
+        format!(
+            "SELECT {} FROM {table} WHERE {where_clause}",
+            super::select_columns(fields)
+        )
+    } else {
+        format!(
+            "UPDATE {table} SET {update_columns} WHERE {where_clause} RETURNING {}",
+            super::select_columns(fields)
+        )
     };
-    let update_string =
-        format!("UPDATE {table} SET {update_columns} WHERE {where_clause} RETURNING *");
     quote! {
-        async fn update(&self, pool: &::sqlx::PgPool) -> ::sqlx::Result<Self> {
-            ::sqlx::query_as!(
-                Self, #update_string, #(self.#all_fields),*
-            )
-            .fetch_one(pool)
-            .await
+        async fn update<'e, E>(&self, executor: E) -> ::sqlx::Result<Self>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            ::sqlx::query!(#update_string, #(#args),*)
+                .try_map(#from_record)
+                #fetch
         }
     }
 }
@@ -1,53 +1,127 @@
 use crate::georm::{GeormField, IdType};
 use quote::quote;
 
-pub fn generate_upsert_query(
+/// Upsert statement and its `query!` arguments. With `check_version`, existing
+/// rows are only updated if their version matches the entity's.
+fn upsert_statement(
     table: &str,
     fields: &[GeormField],
     id: &IdType,
-) -> proc_macro2::TokenStream {
-    let inputs: Vec<String> = (1..=fields.len()).map(|num| format!("${num}")).collect();
-    let columns = fields
-        .iter()
-        .map(|f| f.ident.to_string())
-        .collect::<Vec<String>>()
-        .join(", ");
-
-    let primary_key: proc_macro2::TokenStream = match id {
-        IdType::Simple { field_name, .. } => quote! {#field_name},
-        IdType::Composite { fields, .. } => {
-            let field_names: Vec<syn::Ident> = fields.iter().map(|f| f.name.clone()).collect();
-            quote! {
-                #(#field_names),*
-            }
-        }
+    check_version: bool,
+) -> (String, Vec<proc_macro2::TokenStream>) {
+    let (upserted, overriding) = super::upserted_fields(fields);
+    let columns = super::columns(&upserted);
+
+    let primary_key = match id {
+        IdType::Simple { column, .. } => column.clone(),
+        IdType::Composite { fields, .. } => super::id_columns(fields),
     };
 
-    // For ON CONFLICT DO UPDATE, exclude the ID field from updates
-    let update_assignments = fields
-        .iter()
-        .filter(|f| !f.id)
-        .map(|f| format!("{} = EXCLUDED.{}", f.ident, f.ident))
-        .collect::<Vec<String>>()
-        .join(", ");
+    let update_assignments = super::upsert_assignments(table, &upserted, &primary_key);
+    let condition = match super::version_field(fields) {
+        Some(version) if check_version => format!(
+            " WHERE {table}.{} = EXCLUDED.{}",
+            version.column(),
+            version.column()
+        ),
+        _ => String::new(),
+    };
 
     let upsert_string = format!(
-        "INSERT INTO {table} ({columns}) VALUES ({}) ON CONFLICT ({}) DO UPDATE SET {update_assignments} RETURNING *",
-        inputs.join(", "),
-        primary_key
+        "INSERT INTO {table} ({columns}){overriding} VALUES ({}) ON CONFLICT ({primary_key}) DO UPDATE SET {update_assignments}{condition} RETURNING {}",
+        super::values(&upserted).join(", "),
+        super::select_columns(fields)
     );
 
-    let field_idents: Vec<syn::Ident> = fields.iter().map(|f| f.ident.clone()).collect();
+    let args = super::query_args(&super::bound_fields(&upserted));
+    (upsert_string, args)
+}
 
+pub fn generate_upsert_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (upsert_string, args) = upsert_statement(table, fields, id, false);
     quote! {
-        async fn create_or_update(&self, pool: &::sqlx::PgPool) -> ::sqlx::Result<Self> {
-            ::sqlx::query_as!(
-                Self,
-                #upsert_string,
-                #(self.#field_idents),*
-            )
-            .fetch_one(pool)
+        async fn create_or_update<'e, E>(&self, executor: E) -> ::sqlx::Result<Self>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            ::sqlx::query!(#upsert_string, #(#args),*)
+                .try_map(#from_record)
+            .fetch_one(executor)
             .await
         }
     }
 }
+
+pub fn generate_upsert_versioned_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (upsert_string, args) = upsert_statement(table, fields, id, true);
+    quote! {
+        /// Insert or update this entity like `create_or_update`, but only update an
+        /// existing row whose version matches this entity's.
+        ///
+        /// # Errors
+        /// Returns a [`georm::VersionConflict`] if the row exists with another
+        /// version, and `sqlx::Error` for other database errors.
+        pub async fn create_or_update_versioned<'e, E>(&self, executor: E) -> ::sqlx::Result<Self>
+        where
+            E: ::sqlx::Executor<'e, Database = ::sqlx::Postgres>,
+        {
+            ::sqlx::query!(#upsert_string, #(#args),*)
+                .try_map(#from_record)
+                .fetch_optional(executor)
+                .await?
+                .ok_or_else(|| ::georm::VersionConflict::new(#table).into())
+        }
+    }
+}
+
+pub fn generate_upsert_many_query(
+    table: &str,
+    fields: &[GeormField],
+    id: &IdType,
+    from_record: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (upserted, overriding) = super::upserted_fields(fields);
+    let (insert, bound) = super::insert_from_unnest(table, &upserted, overriding);
+    let id_columns = match id {
+        IdType::Simple { column, .. } => column.clone(),
+        IdType::Composite { fields, .. } => super::id_columns(fields),
+    };
+    let update_assignments = super::upsert_assignments(table, &upserted, &id_columns);
+    let upsert = format!("{insert} ON CONFLICT ({id_columns}) DO UPDATE SET {update_assignments}");
+    let upsert_many_string = super::returning_in_input_order(&upsert, fields, &bound, id);
+
+    let batch_size = super::BATCH_SIZE;
+    let binds = super::batch_binds(&bound);
+
+    quote! {
+        async fn create_or_update_many<'a, A>(entities: &[Self], acquire: A) -> ::sqlx::Result<Vec<Self>>
+        where
+            A: ::sqlx::Acquire<'a, Database = ::sqlx::Postgres> + Send,
+        {
+            if entities.is_empty() {
+                return Ok(Vec::new());
+            }
+            let mut tx = acquire.begin().await?;
+            let mut upserted = Vec::with_capacity(entities.len());
+            for batch in entities.chunks(#batch_size) {
+                let rows = ::sqlx::query!(#upsert_many_string, #(#binds),*)
+                    .try_map(#from_record)
+                    .fetch_all(&mut *tx)
+                    .await?;
+                upserted.extend(rows);
+            }
+            tx.commit().await?;
+            Ok(upserted)
+        }
+    }
+}
@@ -0,0 +1,9 @@
+-- Add up migration script here
+CREATE TABLE legacy_orders (
+    "orderId" SERIAL PRIMARY KEY,
+    "customerName" VARCHAR(100) NOT NULL,
+    "type" VARCHAR(50) NOT NULL,
+    "order" INTEGER NOT NULL DEFAULT 0,
+    "authorId" INTEGER,
+    FOREIGN KEY ("authorId") REFERENCES authors(id) ON DELETE CASCADE
+);
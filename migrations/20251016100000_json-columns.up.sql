@@ -0,0 +1,7 @@
+-- Add up migration script here
+CREATE TABLE preferences (
+    id SERIAL PRIMARY KEY,
+    settings JSONB NOT NULL,
+    metadata JSONB,
+    tags JSONB NOT NULL DEFAULT '[]'
+);
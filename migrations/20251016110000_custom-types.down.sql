@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS tickets;
+DROP DOMAIN IF EXISTS ticket_priority;
+DROP TYPE IF EXISTS ticket_status;
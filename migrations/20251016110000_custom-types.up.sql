@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TYPE ticket_status AS ENUM ('open', 'in_progress', 'closed');
+CREATE DOMAIN ticket_priority AS INTEGER CHECK (VALUE BETWEEN 1 AND 5);
+
+CREATE TABLE tickets (
+    id SERIAL PRIMARY KEY,
+    title TEXT NOT NULL,
+    status ticket_status NOT NULL DEFAULT 'open',
+    previous_status ticket_status,
+    priority ticket_priority NOT NULL,
+    -- Not mapped by the entity
+    opened_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
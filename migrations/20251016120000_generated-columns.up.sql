@@ -0,0 +1,8 @@
+-- Add up migration script here
+CREATE TABLE invoices (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    net_cents INTEGER NOT NULL,
+    tax_cents INTEGER NOT NULL,
+    total_cents INTEGER GENERATED ALWAYS AS (net_cents + tax_cents) STORED,
+    note TEXT NOT NULL DEFAULT ''
+);
@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TABLE documents (
+    id SERIAL PRIMARY KEY,
+    tenant_id INTEGER NOT NULL,
+    created_by TEXT NOT NULL,
+    title TEXT NOT NULL
+);
+
+CREATE TABLE audit_entries (
+    id INTEGER PRIMARY KEY,
+    action TEXT NOT NULL,
+    recorded_by TEXT NOT NULL
+);
@@ -0,0 +1,6 @@
+-- Add up migration script here
+CREATE TABLE wiki_pages (
+    id SERIAL PRIMARY KEY,
+    title TEXT NOT NULL,
+    version INTEGER NOT NULL DEFAULT 1
+);
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS replies;
+DROP TABLE IF EXISTS threads;
+DROP TABLE IF EXISTS forums;
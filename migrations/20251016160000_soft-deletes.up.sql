@@ -0,0 +1,18 @@
+-- Add up migration script here
+CREATE TABLE forums (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE threads (
+    id SERIAL PRIMARY KEY,
+    forum_id INTEGER NOT NULL REFERENCES forums(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE TABLE replies (
+    id SERIAL PRIMARY KEY,
+    thread_id INTEGER NOT NULL REFERENCES threads(id) ON DELETE CASCADE,
+    body TEXT NOT NULL
+);
@@ -0,0 +1,7 @@
+-- Add up migration script here
+CREATE TABLE order_lines (
+    order_id INTEGER NOT NULL,
+    line_number SERIAL NOT NULL,
+    product TEXT NOT NULL,
+    PRIMARY KEY (order_id, line_number)
+);
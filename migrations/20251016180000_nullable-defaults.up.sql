@@ -0,0 +1,6 @@
+-- Add up migration script here
+CREATE TABLE bookmarks (
+    id SERIAL PRIMARY KEY,
+    url TEXT NOT NULL,
+    folder TEXT DEFAULT 'unsorted'
+);
@@ -0,0 +1,5 @@
+-- Add up migration script here
+CREATE TABLE page_views (
+    id SERIAL PRIMARY KEY,
+    viewed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,7 @@
+-- Add up migration script here
+CREATE TABLE accounts (
+    id SERIAL PRIMARY KEY,
+    username TEXT NOT NULL UNIQUE,
+    email TEXT,
+    login_count INTEGER NOT NULL DEFAULT 0
+);
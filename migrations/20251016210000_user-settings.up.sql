@@ -0,0 +1,11 @@
+-- Add up migration script here
+CREATE TABLE user_settings (
+    id SERIAL PRIMARY KEY,
+    username TEXT NOT NULL UNIQUE,
+    theme TEXT NOT NULL DEFAULT 'light',
+    language TEXT NOT NULL DEFAULT 'en',
+    notifications BOOLEAN NOT NULL DEFAULT TRUE,
+    items_per_page INTEGER NOT NULL DEFAULT 20,
+    timezone TEXT DEFAULT 'UTC',
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
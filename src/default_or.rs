@@ -0,0 +1,66 @@
+/// Value of a nullable defaultable field in a generated `{EntityName}Default`
+/// struct.
+///
+/// A nullable column with a database `DEFAULT` can be created in three ways,
+/// which `Option<Option<T>>` would not tell apart clearly:
+/// - [`DefaultOr::Default`] leaves the column out of the `INSERT`, so that the
+///   database default applies
+/// - `DefaultOr::Value(None)` sets the column to `NULL`
+/// - `DefaultOr::Value(Some(value))` sets the column to `value`
+///
+/// # Examples
+/// ```ignore
+/// #[derive(Georm)]
+/// #[georm(table = "posts")]
+/// pub struct Post {
+///     #[georm(id, defaultable)]
+///     id: i32,
+///     title: String,
+///     #[georm(defaultable)]
+///     category: Option<String>,   // DEFAULT 'general'
+/// }
+///
+/// let post = PostDefault {
+///     id: None,
+///     title: "Uncategorized".to_string(),
+///     category: DefaultOr::Value(None),
+/// }
+/// .create(&pool)
+/// .await?;
+/// assert_eq!(None, post.category);
+/// ```
+///
+/// With the `serde` feature, it serializes like any other enum, as `"Default"`
+/// or `{"Value": value}`, so that the three cases stay distinct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DefaultOr<T> {
+    /// Let the database provide the column's default value.
+    #[default]
+    Default,
+    /// Write this value, which may be `None` to write `NULL`.
+    Value(T),
+}
+
+impl<T> DefaultOr<T> {
+    /// Check whether the database default is used.
+    #[must_use]
+    pub const fn is_default(&self) -> bool {
+        matches!(self, Self::Default)
+    }
+
+    /// Get the value to write, `None` if the database default is used.
+    #[must_use]
+    pub const fn as_value(&self) -> Option<&T> {
+        match self {
+            Self::Default => None,
+            Self::Value(value) => Some(value),
+        }
+    }
+}
+
+impl<T> From<T> for DefaultOr<T> {
+    fn from(value: T) -> Self {
+        Self::Value(value)
+    }
+}
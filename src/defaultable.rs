@@ -25,9 +25,18 @@
 /// The `create` method generates SQL that:
 /// - Only includes fields where `Some(value)` is provided
 /// - Omits fields that are `None`, allowing database defaults to apply
-/// - Uses `RETURNING *` to capture the final entity state with all defaults applied
+/// - Uses `RETURNING <columns>` to capture the final entity state with all defaults applied
 /// - Respects database triggers, sequences, and default value expressions
 ///
+/// With up to four defaultable fields, every combination of provided and omitted
+/// fields gets its own statement, checked at compile time like the ones of
+/// [`Georm`](crate::Georm), and `create` picks the one matching the values it is
+/// given. With more, their number would make compilation too slow, so the
+/// statement is built at runtime from the fields provided instead. Only the
+/// statement writing every field is then checked at compile time, along with the
+/// decoding of the returned row: the statement actually run, which leaves out
+/// the fields that are not provided, is not.
+///
 /// ## Usage Examples
 ///
 /// ```ignore
@@ -136,14 +145,17 @@
 ///
 /// ## Limitations and Rules
 ///
-/// - **Option fields cannot be defaultable**: Fields that are already `Option<T>` cannot
-///   be marked with `#[georm(defaultable)]` to prevent `Option<Option<T>>` types
-/// - **Compile-time validation**: Attempts to mark `Option<T>` fields as defaultable
-///   result in compile-time errors
+/// - **Option fields are tri-state**: Defaultable fields that are already `Option<T>`
+///   become a [`DefaultOr<Option<T>>`](crate::DefaultOr), telling the database default
+///   apart from `NULL`
 /// - **Requires at least one defaultable field**: The companion struct is only generated
 ///   if at least one field is marked as defaultable
-/// - **No partial updates**: This trait only supports creating new entities, not updating
-///   existing ones with defaults
+/// - **Checked up to four defaultable fields**: Each combination of them is a
+///   distinct checked statement, above four the statement is built at runtime
+///   and only its version writing every field is checked
+/// - **No partial updates**: Besides upserts, this trait only supports creating new
+///   entities, see the `{EntityName}Patch` struct generated with the `patch`
+///   attribute to update existing ones
 ///
 /// ## Error Handling
 ///
@@ -156,19 +168,20 @@
 /// ## Performance Characteristics
 ///
 /// - **Efficient SQL**: Only includes necessary fields in the INSERT statement
-/// - **Single round-trip**: Uses `RETURNING *` to get the final entity state
-/// - **No overhead**: Defaultable logic is resolved at compile time
+/// - **Single round-trip**: Uses `RETURNING <columns>` to get the final entity state
+/// - **Little overhead**: Up to four defaultable fields, the statement is picked
+///   among ones written at compile time
 /// - **Database-optimized**: Leverages database defaults rather than application logic
 pub trait Defaultable<Id, Entity> {
     /// Create a new entity in the database using database defaults for unspecified fields.
     ///
-    /// This method constructs and executes an `INSERT INTO table_name (...) VALUES (...) RETURNING *`
+    /// This method constructs and executes an `INSERT INTO table_name (...) VALUES (...) RETURNING <columns>`
     /// query that only includes fields where `Some(value)` is provided. Fields that are `None`
     /// are omitted from the query, allowing the database to apply default values, auto-increment
     /// sequences, or trigger-generated values.
     ///
     /// # Parameters
-    /// - `pool` - Database connection pool
+    /// - `executor` - Database executor: a pool, a connection or a transaction
     ///
     /// # Returns
     /// - `Ok(Entity)` - The newly created entity with all database-generated values populated
@@ -183,14 +196,16 @@ pub trait Defaultable<Id, Entity> {
     ///
     /// # SQL Generation
     ///
-    /// The generated SQL dynamically includes only the necessary fields:
+    /// The statement used only includes the necessary fields:
     ///
     /// ```sql
     /// -- If id=None, published=None, created_at=None:
-    /// INSERT INTO posts (title, author_id) VALUES ($1, $2) RETURNING *;
+    /// INSERT INTO posts (title, author_id) VALUES ($1, $2)
+    /// RETURNING id AS "id!: i32", title AS "title!: String", ...;
     ///
     /// -- If id=None, published=Some(true), created_at=None:
-    /// INSERT INTO posts (title, published, author_id) VALUES ($1, $2, $3) RETURNING *;
+    /// INSERT INTO posts (title, published, author_id) VALUES ($1, $2, $3)
+    /// RETURNING id AS "id!: i32", title AS "title!: String", ...;
     /// ```
     ///
     /// # Examples
@@ -243,7 +258,8 @@ pub trait Defaultable<Id, Entity> {
     ///
     /// - **Optimal field selection**: Only transmits necessary data to the database
     /// - **Single database round-trip**: INSERT and retrieval in one operation
-    /// - **Compile-time optimization**: Field inclusion logic resolved at compile time
+    /// - **Compile-time optimization**: Up to four defaultable fields, field inclusion
+    ///   logic is resolved at compile time
     /// - **Database-native defaults**: Leverages database performance for default value generation
     ///
     /// # Comparison with Standard Creation
@@ -269,10 +285,72 @@ pub trait Defaultable<Id, Entity> {
     /// };
     /// let created = post_default.create(&pool).await?;
     /// ```
-    fn create(
+    fn create<'e, E>(
+        &self,
+        executor: E,
+    ) -> impl std::future::Future<Output = sqlx::Result<Entity>> + Send
+    where
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
+
+    /// Insert a new entity like [`create`](Defaultable::create), or update the
+    /// existing one it conflicts with.
+    ///
+    /// This method executes an `INSERT ... ON CONFLICT (...) DO UPDATE SET ... RETURNING ...`
+    /// query. Fields that are `None` are left out of both the insert and the update,
+    /// so an existing row keeps its value for them.
+    ///
+    /// # Parameters
+    /// - `executor` - Database executor: a pool, a connection or a transaction
+    ///
+    /// # Returns
+    /// - `Ok(Entity)` - The final entity state in the database (inserted or updated)
+    /// - `Err(sqlx::Error)` - Database connection or constraint violation errors
+    ///
+    /// # Database Behavior
+    /// - Conflicts are detected on the primary key, or on the columns given by the
+    ///   struct-level `#[georm(on_conflict = "...")]` attribute, which must match a
+    ///   unique constraint or index
+    /// - Upserting on a natural key allows the primary key to be left to its default
+    /// - A struct has a single conflict target: a row conflicting on another unique
+    ///   constraint is an error, not an update. Derive another struct over the same
+    ///   table to upsert on other columns
+    /// - Like [`create`](Defaultable::create), the statement is built at runtime
+    ///   above four defaultable fields
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[derive(Georm)]
+    /// #[georm(table = "users", on_conflict = "username")]
+    /// pub struct User {
+    ///     #[georm(id, defaultable)]
+    ///     id: i32,
+    ///     username: String,
+    ///     #[georm(defaultable)]
+    ///     email: Option<String>,
+    /// }
+    ///
+    /// // Inserted if the username is new, its email updated otherwise
+    /// let user = UserDefault {
+    ///     id: None,
+    ///     username: "alice".to_string(),
+    ///     email: DefaultOr::Value(Some("alice@example.com".to_string())),
+    /// }
+    /// .create_or_update(&pool)
+    /// .await?;
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `sqlx::Error` for:
+    /// - Constraint violations other than the conflict target
+    /// - Database connection issues
+    /// - Permission problems
+    fn create_or_update<'e, E>(
         &self,
-        pool: &sqlx::PgPool,
+        executor: E,
     ) -> impl std::future::Future<Output = sqlx::Result<Entity>> + Send
     where
-        Self: Sized;
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
 }
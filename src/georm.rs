@@ -18,8 +18,16 @@
 ///
 /// ### Static Methods (Query Operations)
 /// - [`find_all`] - Retrieve all entities from the table
+/// - [`find_all_stream`] - Stream all entities from the table
 /// - [`find`] - Retrieve a single entity by primary key
+/// - [`find_many`] - Retrieve every entity matching a list of primary keys
+/// - [`find_page`] - Retrieve one page of entities in primary key order
+/// - [`count`] - Count the entities in the table
+/// - [`exists`] - Check whether an entity exists by primary key
 /// - [`delete_by_id`] - Delete an entity by primary key
+/// - [`delete_many_by_ids`] - Delete every entity matching a list of primary keys
+/// - [`create_many`] - Insert many entities in a single statement per batch
+/// - [`create_or_update_many`] - Upsert many entities in a single statement per batch
 ///
 /// ### Instance Methods (Mutation Operations)
 /// - [`create`] - Insert a new entity into the database
@@ -78,6 +86,22 @@
 /// let user_role = UserRole::find(&pool, &id).await?;
 /// ```
 ///
+/// ## Executors
+///
+/// Every method accepts any [`sqlx::Executor`] for PostgreSQL rather than only a
+/// pool. Passing `&pool` keeps working as before, while passing a connection or an
+/// open transaction lets several operations commit or roll back together:
+///
+/// ```ignore
+/// let mut tx = pool.begin().await?;
+/// let author = author.create(&mut *tx).await?;
+/// let book = book.create(&mut *tx).await?;
+/// tx.commit().await?;
+/// ```
+///
+/// Since executors are consumed by each call, reborrow a connection or a
+/// transaction with `&mut *tx` for every operation.
+///
 /// ## Error Handling
 ///
 /// All methods return `sqlx::Result<T>` and may fail due to:
@@ -87,27 +111,39 @@
 /// - Missing records (for operations expecting existing data)
 ///
 /// [`find_all`]: Georm::find_all
+/// [`find_all_stream`]: Georm::find_all_stream
 /// [`find`]: Georm::find
+/// [`find_many`]: Georm::find_many
+/// [`find_page`]: Georm::find_page
+/// [`count`]: Georm::count
+/// [`exists`]: Georm::exists
 /// [`create`]: Georm::create
+/// [`create_many`]: Georm::create_many
 /// [`update`]: Georm::update
 /// [`create_or_update`]: Georm::create_or_update
+/// [`create_or_update_many`]: Georm::create_or_update_many
 /// [`delete`]: Georm::delete
 /// [`delete_by_id`]: Georm::delete_by_id
+/// [`delete_many_by_ids`]: Georm::delete_many_by_ids
 /// [`get_id`]: Georm::get_id
 pub trait Georm<Id> {
     /// Retrieve all entities from the database table.
     ///
-    /// This method executes a `SELECT * FROM table_name` query and returns all records
-    /// as a vector of entities. The results are not paginated or filtered.
+    /// This method executes a `SELECT <columns> FROM table_name` query, where every
+    /// column is listed explicitly as `col AS "field!: Type"`, followed by
+    /// `ORDER BY ...` when the struct declares `#[georm(order_by = "...")]`, and returns
+    /// all records as a vector of entities. The results are not paginated or filtered.
     ///
     /// # Returns
     /// - `Ok(Vec<Self>)` - All entities in the table (may be empty)
     /// - `Err(sqlx::Error)` - Database connection or query execution errors
     ///
     /// # Performance Notes
-    /// - Returns all records in memory - consider pagination for large tables
+    /// - Returns all records in memory - consider [`find_all_stream`](Georm::find_all_stream)
+    ///   for large tables
     /// - Uses prepared statements for optimal performance
-    /// - No built-in ordering - results may vary between calls
+    /// - Ordered by the struct-level `order_by` attribute if set, otherwise results
+    ///   may vary between calls
     ///
     /// # Examples
     /// ```ignore
@@ -118,19 +154,52 @@ pub trait Georm<Id> {
     /// # Errors
     /// Returns `sqlx::Error` for database connection issues, permission problems,
     /// or if the table doesn't exist.
-    fn find_all(
-        pool: &sqlx::PgPool,
+    fn find_all<'e, E>(
+        executor: E,
     ) -> impl ::std::future::Future<Output = ::sqlx::Result<Vec<Self>>> + Send
     where
-        Self: Sized;
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
+
+    /// Stream all entities from the database table.
+    ///
+    /// This method executes the same `SELECT <columns> FROM table_name` query as
+    /// [`find_all`](Georm::find_all), but yields entities one at a time as rows
+    /// arrive instead of collecting them into a `Vec`. Memory usage stays constant
+    /// regardless of the size of the table.
+    ///
+    /// # Returns
+    /// A stream of `sqlx::Result<Self>`, one item per row, which ends after the last
+    /// row or yields an error if the query fails.
+    ///
+    /// # Performance Notes
+    /// - The executor stays busy until the stream is dropped or exhausted
+    /// - Ordered by the struct-level `order_by` attribute if set, otherwise results
+    ///   may vary between calls
+    ///
+    /// # Examples
+    /// ```ignore
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut users = User::find_all_stream(&pool);
+    /// while let Some(user) = users.try_next().await? {
+    ///     export(&user)?;
+    /// }
+    /// ```
+    fn find_all_stream<'e, E>(
+        executor: E,
+    ) -> impl futures_core::Stream<Item = sqlx::Result<Self>> + Send + 'e
+    where
+        Self: Sized + 'e,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres> + 'e;
 
     /// Find a single entity by its primary key.
     ///
-    /// This method executes a `SELECT * FROM table_name WHERE primary_key = $1` query
+    /// This method executes a `SELECT <columns> FROM table_name WHERE primary_key = $1` query
     /// (or equivalent for composite keys) and returns the matching entity if found.
     ///
     /// # Parameters
-    /// - `pool` - Database connection pool
+    /// - `executor` - Database executor: a pool, a connection or a transaction
     /// - `id` - Primary key value (simple type or composite key struct)
     ///
     /// # Returns
@@ -152,28 +221,174 @@ pub trait Georm<Id> {
     /// Returns `sqlx::Error` for database connection issues, type conversion errors,
     /// or query execution problems. Note that not finding a record is not an error
     /// - it returns `Ok(None)`.
-    fn find(
-        pool: &sqlx::PgPool,
+    fn find<'e, E>(
+        executor: E,
         id: &Id,
     ) -> impl std::future::Future<Output = sqlx::Result<Option<Self>>> + Send
     where
-        Self: Sized;
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
+
+    /// Find every entity whose primary key is in `ids`.
+    ///
+    /// This method executes a single `SELECT <columns> FROM table_name WHERE primary_key = ANY($1)`
+    /// query for simple keys, or `WHERE (key_a, key_b) IN (SELECT * FROM UNNEST($1, $2))`
+    /// for composite keys, instead of one query per identifier.
+    ///
+    /// # Parameters
+    /// - `executor` - Database executor: a pool, a connection or a transaction
+    /// - `ids` - Primary key values (simple types or composite key structs)
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Self>)` - The entities found, in no particular order
+    /// - `Err(sqlx::Error)` - Database connection or query execution errors
+    ///
+    /// Identifiers without a matching entity are silently skipped, and duplicate
+    /// identifiers only return their entity once.
+    ///
+    /// # Examples
+    /// ```ignore
+    /// // Simple primary key
+    /// let users = User::find_many(&pool, &[1, 2, 3]).await?;
+    ///
+    /// // Composite primary key
+    /// let ids = [UserRoleId { user_id: 1, role_id: 2 }, UserRoleId { user_id: 3, role_id: 1 }];
+    /// let user_roles = UserRole::find_many(&pool, &ids).await?;
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `sqlx::Error` for database connection issues, type conversion errors,
+    /// or query execution problems.
+    fn find_many<'e, E>(
+        executor: E,
+        ids: &[Id],
+    ) -> impl std::future::Future<Output = sqlx::Result<Vec<Self>>> + Send
+    where
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
+
+    /// Retrieve one page of entities, in primary key order.
+    ///
+    /// This method uses keyset pagination: instead of an `OFFSET`, each page starts
+    /// right after the primary key of the last entity of the previous page, with a
+    /// `WHERE (primary_key) > (cursor) ORDER BY primary_key LIMIT n` query. Pages stay
+    /// fast however deep they are, and entities inserted or deleted between two calls
+    /// never cause rows to be skipped or returned twice. Composite keys are compared
+    /// as rows, column by column in declaration order.
+    ///
+    /// # Parameters
+    /// - `executor` - Database executor: a pool, a connection or a transaction
+    /// - `request` - Page size, and cursor of the previous page if any
+    ///
+    /// # Returns
+    /// - `Ok(Page<Self>)` - Up to `request.limit` entities, at least one, along with the
+    ///   cursor to request the next page with, which is `None` once the last page is reached
+    /// - `Err(sqlx::Error)` - Database connection or query execution errors
+    ///
+    /// # Examples
+    /// ```ignore
+    /// use georm::PageRequest;
+    ///
+    /// let page = User::find_page(&pool, PageRequest::first(20)).await?;
+    ///
+    /// // Cursors are opaque strings that can be sent to clients…
+    /// let token = page.next_cursor.map(|cursor| cursor.to_string());
+    ///
+    /// // …and parsed back when they request the next page
+    /// let cursor = token.unwrap().parse()?;
+    /// let next_page = User::find_page(&pool, PageRequest::after(cursor, 20)).await?;
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `sqlx::Error` for database connection issues, type conversion errors,
+    /// or query execution problems, and an [`InvalidCursor`](crate::InvalidCursor)
+    /// decoding error for a cursor that comes from an entity stored in another table.
+    fn find_page<'e, E>(
+        executor: E,
+        request: crate::PageRequest,
+    ) -> impl std::future::Future<Output = sqlx::Result<crate::Page<Self>>> + Send
+    where
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
+
+    /// Count the entities in the database table.
+    ///
+    /// This method executes a `SELECT COUNT(*) FROM table_name` query, without
+    /// fetching any row.
+    ///
+    /// # Parameters
+    /// - `executor` - Database executor: a pool, a connection or a transaction
+    ///
+    /// # Returns
+    /// - `Ok(i64)` - Number of entities in the table
+    /// - `Err(sqlx::Error)` - Database connection or query execution errors
+    ///
+    /// # Examples
+    /// ```ignore
+    /// let user_count = User::count(&pool).await?;
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `sqlx::Error` for database connection issues, permission problems,
+    /// or if the table doesn't exist.
+    fn count<'e, E>(executor: E) -> impl std::future::Future<Output = sqlx::Result<i64>> + Send
+    where
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
+
+    /// Check whether an entity exists by its primary key.
+    ///
+    /// This method executes a `SELECT EXISTS(SELECT 1 FROM table_name WHERE primary_key = $1)`
+    /// query (or equivalent for composite keys). Unlike `find(...).await?.is_some()`,
+    /// the row is never fetched nor deserialized.
+    ///
+    /// # Parameters
+    /// - `executor` - Database executor: a pool, a connection or a transaction
+    /// - `id` - Primary key value (simple type or composite key struct)
+    ///
+    /// # Returns
+    /// - `Ok(true)` - An entity with the given ID exists
+    /// - `Ok(false)` - No entity with the given ID exists
+    /// - `Err(sqlx::Error)` - Database connection or query execution errors
+    ///
+    /// # Examples
+    /// ```ignore
+    /// // Simple primary key
+    /// if !User::exists(&pool, &1).await? {
+    ///     return Err(NotFound);
+    /// }
+    ///
+    /// // Composite primary key
+    /// let id = UserRoleId { user_id: 1, role_id: 2 };
+    /// let has_role = UserRole::exists(&pool, &id).await?;
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `sqlx::Error` for database connection issues, type conversion errors,
+    /// or query execution problems.
+    fn exists<'e, E>(
+        executor: E,
+        id: &Id,
+    ) -> impl std::future::Future<Output = sqlx::Result<bool>> + Send
+    where
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
 
     /// Insert this entity as a new record in the database.
     ///
-    /// This method executes an `INSERT INTO table_name (...) VALUES (...) RETURNING *`
+    /// This method executes an `INSERT INTO table_name (...) VALUES (...) RETURNING <columns>`
     /// query and returns the newly created entity with any database-generated values
     /// (such as auto-increment IDs, default timestamps, etc.).
     ///
     /// # Parameters
-    /// - `pool` - Database connection pool
+    /// - `executor` - Database executor: a pool, a connection or a transaction
     ///
     /// # Returns
     /// - `Ok(Self)` - The entity as it exists in the database after insertion
     /// - `Err(sqlx::Error)` - Database constraint violations or connection errors
     ///
     /// # Database Behavior
-    /// - Uses `RETURNING *` to capture database-generated values
+    /// - Uses `RETURNING <columns>` to capture database-generated values
     /// - Respects database defaults for fields marked `#[georm(defaultable)]`
     /// - Triggers and database-side modifications are reflected in the returned entity
     ///
@@ -191,27 +406,76 @@ pub trait Georm<Id> {
     /// - NOT NULL constraint violations
     /// - Database connection issues
     /// - Permission problems
-    fn create(
+    fn create<'e, E>(
         &self,
-        pool: &sqlx::PgPool,
+        executor: E,
     ) -> impl std::future::Future<Output = sqlx::Result<Self>> + Send
     where
-        Self: Sized;
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
+
+    /// Insert many entities at once.
+    ///
+    /// This method executes an `INSERT INTO table_name (...) SELECT ... FROM UNNEST(...)
+    /// WITH ORDINALITY RETURNING ...` query, binding one array per column instead of
+    /// issuing one `INSERT` per entity. The statement has one parameter per column whatever the
+    /// number of entities, but slices are still split into batches of 10,000
+    /// entities to bound the size of each array and the memory used by a batch.
+    ///
+    /// # Parameters
+    /// - `entities` - The entities to insert
+    /// - `acquire` - Database pool, connection, or transaction
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Self>)` - The inserted entities as they exist in the database, in input order
+    /// - `Err(sqlx::Error)` - Database constraint violations or connection errors
+    ///
+    /// # Database Behavior
+    /// - All batches run inside a single transaction (a savepoint if one is already
+    ///   open), so either every entity is inserted or none is
+    /// - An empty slice returns immediately without querying the database
+    /// - Every field type must implement `sqlx::postgres::PgHasArrayType`
+    /// - Rows are matched back to their input by primary key to be returned in
+    ///   input order. Keys generated by the database cannot be, so such entities
+    ///   are returned in insertion order, which follows the input
+    ///
+    /// # Examples
+    /// ```ignore
+    /// let users = vec![
+    ///     User { id: 1, username: "alice".into(), email: "alice@example.com".into() },
+    ///     User { id: 2, username: "bob".into(), email: "bob@example.com".into() },
+    /// ];
+    /// let created = User::create_many(&users, &pool).await?;
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `sqlx::Error` for:
+    /// - Unique constraint violations
+    /// - Foreign key constraint violations
+    /// - NOT NULL constraint violations
+    /// - Database connection issues
+    fn create_many<'a, A>(
+        entities: &[Self],
+        acquire: A,
+    ) -> impl std::future::Future<Output = sqlx::Result<Vec<Self>>> + Send
+    where
+        Self: Sized,
+        A: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
 
     /// Update an existing entity in the database.
     ///
-    /// This method executes an `UPDATE table_name SET ... WHERE primary_key = ... RETURNING *`
+    /// This method executes an `UPDATE table_name SET ... WHERE primary_key = ... RETURNING <columns>`
     /// query using the entity's current primary key to locate the record to update.
     ///
     /// # Parameters
-    /// - `pool` - Database connection pool
+    /// - `executor` - Database executor: a pool, a connection or a transaction
     ///
     /// # Returns
     /// - `Ok(Self)` - The entity as it exists in the database after the update
     /// - `Err(sqlx::Error)` - Database errors or if no matching record exists
     ///
     /// # Database Behavior
-    /// - Uses `RETURNING *` to capture any database-side changes
+    /// - Uses `RETURNING <columns>` to capture any database-side changes
     /// - Updates all fields, not just changed ones
     /// - Triggers and database-side modifications are reflected in the returned entity
     /// - Fails if no record with the current primary key exists
@@ -223,27 +487,37 @@ pub trait Georm<Id> {
     /// let updated_user = user.update(&pool).await?;
     /// ```
     ///
+    /// # Versioned Entities
+    /// If the entity has a `#[georm(version)]` field, the record is only updated if
+    /// its version matches the entity's, and its version is incremented.
+    ///
+    /// # Soft-Deleted Entities
+    /// Soft-deleted records are updated like any other and stay deleted.
+    ///
     /// # Errors
     /// Returns `sqlx::Error` for:
     /// - No matching record found (record was deleted by another process)
+    /// - A [`VersionConflict`](crate::VersionConflict) if the entity is versioned
+    ///   and no record has its primary key and version
     /// - Constraint violations (unique, foreign key, etc.)
     /// - Database connection issues
     /// - Permission problems
-    fn update(
+    fn update<'e, E>(
         &self,
-        pool: &sqlx::PgPool,
+        executor: E,
     ) -> impl std::future::Future<Output = sqlx::Result<Self>> + Send
     where
-        Self: Sized;
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
 
     /// Insert or update this entity using PostgreSQL's upsert functionality.
     ///
-    /// This method executes an `INSERT ... ON CONFLICT (...) DO UPDATE SET ... RETURNING *`
+    /// This method executes an `INSERT ... ON CONFLICT (...) DO UPDATE SET ... RETURNING <columns>`
     /// query that atomically inserts the entity if it doesn't exist, or updates it if
     /// a record with the same primary key already exists.
     ///
     /// # Parameters
-    /// - `pool` - Database connection pool
+    /// - `executor` - Database executor: a pool, a connection or a transaction
     ///
     /// # Returns
     /// - `Ok(Self)` - The final entity state in the database (inserted or updated)
@@ -252,8 +526,9 @@ pub trait Georm<Id> {
     /// # Database Behavior
     /// - Uses PostgreSQL's `ON CONFLICT` for true atomic upsert
     /// - More efficient than separate find-then-create-or-update logic
-    /// - Uses `RETURNING *` to capture the final state
+    /// - Uses `RETURNING <columns>` to capture the final state
     /// - Conflict resolution is based on the primary key constraint
+    /// - Soft-deleted records are updated like any other and stay deleted
     ///
     /// # Examples
     /// ```ignore
@@ -267,12 +542,57 @@ pub trait Georm<Id> {
     /// - Non-primary-key constraint violations
     /// - Database connection issues
     /// - Permission problems
-    fn create_or_update(
+    fn create_or_update<'e, E>(
         &self,
-        pool: &sqlx::PgPool,
+        executor: E,
     ) -> impl ::std::future::Future<Output = sqlx::Result<Self>>
     where
-        Self: Sized;
+        Self: Sized,
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
+
+    /// Insert or update many entities at once.
+    ///
+    /// This is the batched version of [`create_or_update`](Georm::create_or_update). It
+    /// executes a single `INSERT ... SELECT * FROM UNNEST(...) ON CONFLICT (...) DO UPDATE
+    /// SET ... RETURNING <columns>` statement per batch, binding one array per column. Like
+    /// [`create_many`](Georm::create_many), slices are split into batches of 10,000
+    /// entities.
+    ///
+    /// # Parameters
+    /// - `entities` - The entities to insert or update
+    /// - `acquire` - Database pool, connection, or transaction
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Self>)` - The final state of every entity, in input order
+    /// - `Err(sqlx::Error)` - Database connection or constraint violation errors
+    ///
+    /// # Database Behavior
+    /// - Conflict resolution is based on the primary key constraint, simple or composite
+    /// - All batches run inside a single transaction (a savepoint if one is already
+    ///   open), so either every entity is written or none is
+    /// - An empty slice returns immediately without querying the database
+    /// - Every field type must implement `sqlx::postgres::PgHasArrayType`
+    ///
+    /// # Examples
+    /// ```ignore
+    /// let catalog: Vec<Product> = fetch_external_catalog().await?;
+    /// let synced = Product::create_or_update_many(&catalog, &pool).await?;
+    /// assert_eq!(catalog.len(), synced.len());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `sqlx::Error` for:
+    /// - The same primary key appearing twice in one batch (PostgreSQL cannot
+    ///   affect a row twice in a single statement)
+    /// - Non-primary-key constraint violations
+    /// - Database connection issues
+    fn create_or_update_many<'a, A>(
+        entities: &[Self],
+        acquire: A,
+    ) -> impl std::future::Future<Output = sqlx::Result<Vec<Self>>> + Send
+    where
+        Self: Sized,
+        A: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
 
     /// Delete this entity from the database.
     ///
@@ -280,7 +600,7 @@ pub trait Georm<Id> {
     /// using this entity's primary key to identify the record to delete.
     ///
     /// # Parameters
-    /// - `pool` - Database connection pool
+    /// - `executor` - Database executor: a pool, a connection or a transaction
     ///
     /// # Returns
     /// - `Ok(u64)` - Number of rows affected (0 if entity didn't exist, 1 if deleted)
@@ -290,6 +610,7 @@ pub trait Georm<Id> {
     /// - Uses the entity's current primary key for deletion
     /// - Returns 0 if no matching record exists (not an error)
     /// - May fail due to foreign key constraints if other records reference this entity
+    /// - Only sets the `#[georm(soft_delete)]` field, if any, instead of deleting the row
     ///
     /// # Examples
     /// ```ignore
@@ -303,10 +624,12 @@ pub trait Georm<Id> {
     /// - Foreign key constraint violations (referenced by other tables)
     /// - Database connection issues
     /// - Permission problems
-    fn delete(
+    fn delete<'e, E>(
         &self,
-        pool: &sqlx::PgPool,
-    ) -> impl std::future::Future<Output = sqlx::Result<u64>> + Send;
+        executor: E,
+    ) -> impl std::future::Future<Output = sqlx::Result<u64>> + Send
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
 
     /// Delete an entity by its primary key without needing an entity instance.
     ///
@@ -314,7 +637,7 @@ pub trait Georm<Id> {
     /// using the provided ID to identify the record to delete.
     ///
     /// # Parameters
-    /// - `pool` - Database connection pool
+    /// - `executor` - Database executor: a pool, a connection or a transaction
     /// - `id` - Primary key value (simple type or composite key struct)
     ///
     /// # Returns
@@ -325,6 +648,7 @@ pub trait Georm<Id> {
     /// - More efficient than `find().delete()` when you only have the ID
     /// - Returns 0 if no matching record exists (not an error)
     /// - May fail due to foreign key constraints if other records reference this entity
+    /// - Only sets the `#[georm(soft_delete)]` field, if any, instead of deleting the row
     ///
     /// # Examples
     /// ```ignore
@@ -341,10 +665,43 @@ pub trait Georm<Id> {
     /// - Foreign key constraint violations (referenced by other tables)
     /// - Database connection issues
     /// - Permission problems
-    fn delete_by_id(
-        pool: &sqlx::PgPool,
+    fn delete_by_id<'e, E>(
+        executor: E,
         id: &Id,
-    ) -> impl std::future::Future<Output = sqlx::Result<u64>> + Send;
+    ) -> impl std::future::Future<Output = sqlx::Result<u64>> + Send
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
+
+    /// Delete every entity whose primary key is in `ids`.
+    ///
+    /// This method executes a single `DELETE FROM table_name WHERE primary_key = ANY($1)`
+    /// query for simple keys, or `WHERE (key_a, key_b) IN (SELECT * FROM UNNEST($1, $2))`
+    /// for composite keys.
+    ///
+    /// # Parameters
+    /// - `executor` - Database executor: a pool, a connection or a transaction
+    /// - `ids` - Primary key values (simple types or composite key structs)
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of rows deleted (identifiers without a match are ignored)
+    /// - `Err(sqlx::Error)` - Database connection or constraint violation errors
+    ///
+    /// # Examples
+    /// ```ignore
+    /// let deleted_count = User::delete_many_by_ids(&pool, &[1, 2, 3]).await?;
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `sqlx::Error` for:
+    /// - Foreign key constraint violations (referenced by other tables)
+    /// - Database connection issues
+    /// - Permission problems
+    fn delete_many_by_ids<'e, E>(
+        executor: E,
+        ids: &[Id],
+    ) -> impl std::future::Future<Output = sqlx::Result<u64>> + Send
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>;
 
     /// Get the primary key of this entity.
     ///
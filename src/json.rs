@@ -0,0 +1,13 @@
+//! Support for fields marked with `#[georm(json)]`, enabled by the `json`
+//! feature. Used by the code generated by `#[derive(Georm)]`.
+
+/// Deserialize a JSON column read by `sqlx::query!` into the field's type.
+///
+/// # Errors
+/// Returns `sqlx::Error::Decode` if the value does not match the field's type.
+pub fn decode<T>(value: sqlx::types::JsonValue) -> sqlx::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_json::from_value(value).map_err(|error| sqlx::Error::Decode(Box::new(error)))
+}
@@ -1,6 +1,6 @@
 //! # Georm
 //!
-//! A simple, type-safe PostgreSQL ORM built on SQLx with zero runtime overhead.
+//! A simple, type-safe PostgreSQL ORM built on SQLx with minimal runtime overhead.
 //!
 //! ## Quick Start
 //!
@@ -27,8 +27,16 @@
 //!
 //! ### Static Methods (called on the struct type)
 //! - `Entity::find(pool, &id)` - Find by primary key, returns `Option<Entity>`
+//! - `Entity::find_many(pool, &ids)` - Find by a list of primary keys, returns `Vec<Entity>`
 //! - `Entity::find_all(pool)` - Get all records, returns `Vec<Entity>`
+//! - `Entity::find_all_stream(pool)` - Stream all records, returns `impl Stream<Item = sqlx::Result<Entity>>`
+//! - `Entity::find_page(pool, request)` - Keyset pagination in primary key order, returns `Page<Entity>`
+//! - `Entity::count(pool)` - Count all records, returns `i64`
+//! - `Entity::exists(pool, &id)` - Check for a primary key without fetching the row, returns `bool`
 //! - `Entity::delete_by_id(pool, &id)` - Delete by ID, returns affected row count
+//! - `Entity::delete_many_by_ids(pool, &ids)` - Delete by a list of IDs, returns affected row count
+//! - `Entity::create_many(&entities, pool)` - Bulk insert using `UNNEST`, returns created entities
+//! - `Entity::create_or_update_many(&entities, pool)` - Bulk upsert, returns final entities in input order
 //!
 //! ### Instance Methods (called on entity objects)
 //! - `entity.create(pool)` - Insert new record, returns created entity with database-generated values
@@ -54,10 +62,42 @@
 //!
 //! Georm leverages PostgreSQL-specific features for performance and reliability:
 //!
-//! - **RETURNING clause**: All `INSERT` and `UPDATE` operations use `RETURNING *` to capture database-generated values (sequences, defaults, triggers)
+//! - **RETURNING clause**: All `INSERT` and `UPDATE` operations list every column in their `RETURNING` clause to capture database-generated values (sequences, defaults, triggers)
 //! - **True upserts**: `create_or_update()` uses `INSERT ... ON CONFLICT ... DO UPDATE` for atomic upsert operations
 //! - **Prepared statements**: All queries use parameter binding for security and performance
-//! - **Compile-time verification**: SQLx macros verify all generated SQL against your database schema at compile time
+//! - **Compile-time verification**: SQLx macros verify the generated SQL against your database schema at compile time
+//!
+//! ### Executors
+//!
+//! Every generated method accepts any PostgreSQL [`sqlx::Executor`]: a pool, a
+//! connection, or a transaction. Passing `&pool` works as shown above, and
+//! passing a transaction groups several writes into a single atomic unit:
+//!
+//! ```ignore
+//! let mut tx = pool.begin().await?;
+//! let user = new_user.create(&mut *tx).await?;
+//! let profile = new_profile.create(&mut *tx).await?;
+//! tx.commit().await?; // or tx.rollback().await? to discard both writes
+//! ```
+//!
+//! ### Transactions
+//!
+//! [`transaction`] runs an async closure inside a transaction, committing when it
+//! returns `Ok` and rolling back when it returns `Err`. Calling it on a connection
+//! that already has an open transaction creates a `SAVEPOINT` instead, so helpers
+//! can be composed into larger units of work:
+//!
+//! ```ignore
+//! let profile = georm::transaction(&pool, async |conn| {
+//!     let user = new_user.create(&mut *conn).await?;
+//!     // Nested call: rolled back to its savepoint on error
+//!     georm::transaction(&mut *conn, async |conn| {
+//!         Profile { user_id: user.id, ..new_profile }.create(&mut *conn).await
+//!     })
+//!     .await
+//! })
+//! .await?;
+//! ```
 //!
 //! ## Primary Keys and Identifiers
 //!
@@ -159,7 +199,9 @@
 //! }
 //!
 //! impl Defaultable<i32, Post> for PostDefault {
-//!     async fn create(&self, pool: &sqlx::PgPool) -> sqlx::Result<Post>;
+//!     async fn create<'e, E>(&self, executor: E) -> sqlx::Result<Post>
+//!     where
+//!         E: sqlx::Executor<'e, Database = sqlx::Postgres>;
 //! }
 //! ```
 //!
@@ -183,24 +225,379 @@
 //! println!("Created post with ID: {}", created_post.id);
 //! ```
 //!
+//! ### Builder
+//!
+//! Entities with defaultable fields also get a `builder()` method, so that new
+//! defaultable columns do not require updating every struct literal. Fields that
+//! are not defaultable must be set before `build` can be called, which is checked
+//! at compile time, while defaultable ones keep their database default unless set.
+//!
+//! ```ignore
+//! let post = Post::builder()
+//!     .title("My Post".to_string())
+//!     .author_id(42)
+//!     .published(true)
+//!     .build()
+//!     .create(&pool)
+//!     .await?;
+//! ```
+//!
+//! ### Upserts
+//!
+//! `Defaultable::create_or_update` inserts the entity or updates the row it
+//! conflicts with, only writing the fields that are provided. Conflicts are
+//! detected on the primary key, or on the columns of the struct-level
+//! `on_conflict` attribute, so that rows can be upserted on a natural key while
+//! their primary key is left to its default.
+//!
+//! ```ignore
+//! #[derive(Georm)]
+//! #[georm(table = "users", on_conflict = "username")]
+//! pub struct User {
+//!     #[georm(id, defaultable)]
+//!     id: i32,
+//!     username: String,
+//!     #[georm(defaultable)]
+//!     last_login: Option<DateTime<Utc>>,
+//! }
+//!
+//! // Inserts alice, or updates the last login of the existing alice
+//! let user = User::builder()
+//!     .username("alice".to_string())
+//!     .last_login(Some(Utc::now()))
+//!     .build()
+//!     .create_or_update(&pool)
+//!     .await?;
+//! ```
+//!
+//! Each struct has a single conflict target: a row conflicting on another unique
+//! constraint fails with a constraint violation instead of being updated. To
+//! upsert on other columns, derive another struct over the same table.
+//!
+//! ### Conversions
+//!
+//! The defaultable struct implements `From<EntityName>`, with every defaultable
+//! field provided, which makes copying an entity without its primary key easy.
+//! It also implements `Default`, leaving every defaultable field to the database,
+//! as long as all its other fields implement `Default` too. Other traits, such as
+//! `Debug`, `Clone` or serde's, can be derived with the struct-level
+//! `default_derive` attribute. `Default` cannot be listed there, and serde's
+//! traits need the `serde` feature when the struct has `DefaultOr` fields.
+//!
+//! ```ignore
+//! #[derive(Georm)]
+//! #[georm(table = "posts", default_derive = [Debug, Clone, serde::Deserialize])]
+//! pub struct Post { /* ... */ }
+//!
+//! let copy = PostDefault {
+//!     id: None,
+//!     ..PostDefault::from(post)
+//! }
+//! .create(&pool)
+//! .await?;
+//! ```
+//!
 //! ### Defaultable Rules and Limitations
 //!
-//! - **Option fields cannot be marked as defaultable**: If a field is already
-//!   `Option<T>`, you cannot mark it with `#[georm(defaultable)]`. This prevents
-//!   `Option<Option<T>>` types and causes a compile-time error.
+//! - **Option fields become tri-state**: A defaultable `Option<T>` field, for a
+//!   nullable column with a `DEFAULT`, becomes a [`DefaultOr<Option<T>>`](DefaultOr)
+//!   whose `Default` variant uses the database default and `Value(None)` writes
+//!   `NULL`.
 //! - **Field visibility is preserved**: The generated defaultable struct maintains
 //!   the same field visibility (`pub`, `pub(crate)`, private) as the original struct.
 //! - **ID fields can be defaultable**: It's common to mark ID fields as defaultable
-//!   when they are auto-generated serials in PostgreSQL.
+//!   when they are auto-generated serials in PostgreSQL. This includes the fields
+//!   of a composite key, whose defaultable struct implements
+//!   `Defaultable<{EntityName}Id, EntityName>`.
 //! - **Only generates when needed**: The defaultable struct is only generated if
 //!   at least one field is marked as defaultable.
+//! - **Checked at compile time**: Up to four defaultable fields, each combination
+//!   of provided ones gets its own `query!` statement. With more, the statement is
+//!   built at runtime from the fields provided, to keep compile times reasonable,
+//!   and only the statement writing every field is checked at compile time.
+//!
+//! ## Partial Updates
+//!
+//! Entities with the struct-level `patch` attribute also get a `<Entity>Patch`
+//! struct, where each field that `update` writes becomes an `Option<T>`. Its
+//! `apply` method updates the entity with the given primary key, only writing the
+//! fields that are `Some`, so that concurrent edits to other columns are not
+//! overwritten. `Option` fields become `Option<Option<T>>`, `Some(None)` setting
+//! the column to `NULL`.
+//!
+//! ```ignore
+//! #[derive(Georm)]
+//! #[georm(table = "posts", patch)]
+//! pub struct Post { /* ... */ }
+//!
+//! let patch = PostPatch {
+//!     title: Some("New title".to_string()),
+//!     ..Default::default()
+//! };
+//! let post = patch.apply(pool, &post_id).await?;
+//! ```
+//!
+//! `updated_at` timestamps are set and versions are incremented, without being
+//! checked, whenever a field is written. Since the written columns depend on the
+//! fields that are set, the query of `apply` is built at runtime and, unlike the
+//! other generated queries, is not checked at compile time.
+//!
+//! ## Column Names
+//!
+//! Columns are named after their field by default. Use `#[georm(column = "...")]`
+//! to map a field to another column, or the struct-level `rename_all` attribute to
+//! apply a case convention to every field: `"lowercase"`, `"UPPERCASE"`,
+//! `"PascalCase"`, `"camelCase"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"`,
+//! `"kebab-case"` or `"SCREAMING-KEBAB-CASE"`. A field-level `column` takes
+//! precedence over `rename_all`.
+//!
+//! ```ignore
+//! #[derive(Georm)]
+//! #[georm(table = "legacy_orders", rename_all = "camelCase")]
+//! pub struct LegacyOrder {
+//!     #[georm(id)]
+//!     order_id: i32,          // "orderId"
+//!     customer_name: String,  // "customerName"
+//!     #[georm(column = "type")]
+//!     kind: String,           // "type"
+//! }
+//! ```
+//!
+//! Renamed columns are quoted in the generated SQL, so their case is preserved
+//! and reserved words such as `type` or `order` can be used. Raw identifiers
+//! such as `r#type` are mapped to their unprefixed column. Column names written
+//! in relationship attributes, like `remote_id`, are used as-is and must be
+//! quoted by hand when needed.
+//!
+//! ## Skipped Fields
+//!
+//! Fields marked with `#[georm(skip)]` are not mapped to any column. They are left
+//! out of every generated query and of the `{Entity}Default` struct, and are filled
+//! with `Default::default()` whenever an entity is read from the database. Use
+//! `default = path::to::function` to provide another value.
+//!
+//! ```ignore
+//! #[derive(Georm)]
+//! #[georm(table = "authors")]
+//! pub struct Author {
+//!     #[georm(id)]
+//!     id: i32,
+//!     name: String,
+//!     #[georm(skip)]
+//!     cached_posts: Vec<Post>,       // Always empty when read
+//!     #[georm(skip, default = Instant::now)]
+//!     loaded_at: Instant,
+//! }
+//! ```
+//!
+//! Skipped fields cannot be identifiers, relations, or defaultable.
+//!
+//! ## JSON Fields
+//!
+//! With the `json` feature, fields marked with `#[georm(json)]` are stored in a
+//! `JSON` or `JSONB` column through `sqlx::types::Json`, which lets any type implementing `Serialize` and
+//! `DeserializeOwned` be used without wrapping it. An `Option` field maps to a
+//! nullable column.
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! pub struct Settings {
+//!     theme: String,
+//!     notifications: bool,
+//! }
+//!
+//! #[derive(Georm)]
+//! #[georm(table = "preferences")]
+//! pub struct Preference {
+//!     #[georm(id, defaultable)]
+//!     id: i32,
+//!     #[georm(json)]
+//!     settings: Settings,
+//!     #[georm(json)]
+//!     metadata: Option<HashMap<String, String>>,
+//! }
+//! ```
+//!
+//! JSON fields cannot be identifiers or skipped. A value that cannot be deserialized
+//! into the field's type is reported as `sqlx::Error::Decode`.
+//!
+//! ## Custom Types
+//!
+//! Generated queries list their columns explicitly, each with a type override
+//! taken from its field, so columns of custom PostgreSQL types such as enums map
+//! to any type implementing `sqlx::Type`, and columns the entity does not map are
+//! ignored. Use `#[georm(sql_type = "...")]` to cast a column the field's type
+//! cannot be decoded from directly, such as a domain, when it is read.
+//!
+//! ```ignore
+//! #[derive(sqlx::Type)]
+//! #[sqlx(type_name = "ticket_status", rename_all = "snake_case")]
+//! pub enum TicketStatus {
+//!     Open,
+//!     Closed,
+//! }
+//!
+//! #[derive(Georm)]
+//! #[georm(table = "tickets")]
+//! pub struct Ticket {
+//!     #[georm(id, defaultable)]
+//!     id: i32,
+//!     status: TicketStatus,
+//!     #[georm(sql_type = "integer")]
+//!     priority: i32,                 // Column of a domain over INTEGER
+//! }
+//! ```
+//!
+//! Type overrides must fit in a PostgreSQL identifier, 63 bytes including the
+//! field's name. Longer ones are left out, which is fine for built-in types such
+//! as `chrono::DateTime<chrono::Utc>`; use a type alias for custom types with
+//! longer paths.
+//!
+//! ## Generated Columns
+//!
+//! Fields marked with `#[georm(generated)]` map to columns computed by the
+//! database, such as `GENERATED ALWAYS AS (...) STORED` or identity columns. They
+//! are never written by `create`, `update`, or `Defaultable::create`, nor part of
+//! `{Entity}Default`, but are read back after every write.
+//!
+//! ```ignore
+//! #[derive(Georm)]
+//! #[georm(table = "invoices")]
+//! pub struct Invoice {
+//!     #[georm(id, generated)]
+//!     id: i32,                       // GENERATED ALWAYS AS IDENTITY
+//!     net_cents: i32,
+//!     tax_cents: i32,
+//!     #[georm(generated)]
+//!     total_cents: i32,              // GENERATED ALWAYS AS (net_cents + tax_cents) STORED
+//! }
+//! ```
+//!
+//! A generated primary key must be an identity column: `create_or_update` still
+//! writes it, with `OVERRIDING SYSTEM VALUE`, to find the row to update. Generated
+//! fields cannot be skipped or defaultable.
+//!
+//! ## Immutable Fields
+//!
+//! Fields marked with `#[georm(immutable)]` are written when the entity is
+//! created, including by the insert half of `create_or_update`, but never by
+//! `update` or when `create_or_update` updates an existing row. This keeps a stale
+//! entity from overwriting columns such as `created_by` or `tenant_id`.
+//!
+//! ```ignore
+//! #[derive(Georm)]
+//! #[georm(table = "documents")]
+//! pub struct Document {
+//!     #[georm(id)]
+//!     id: i32,
+//!     #[georm(immutable)]
+//!     created_by: String,
+//!     title: String,
+//! }
+//! ```
+//!
+//! Immutable fields cannot be skipped or generated.
+//!
+//! ## Timestamps
+//!
+//! Fields marked with `#[georm(created_at)]` or `#[georm(updated_at)]` are set to
+//! `now()` by the database instead of being bound, so their value in the entity is
+//! ignored when writing. `created_at` is set when a row is inserted and never
+//! changed afterwards, while `updated_at` is set on every write. Neither is part of
+//! `{Entity}Default`.
+//!
+//! ```ignore
+//! #[derive(Georm)]
+//! #[georm(table = "notes")]
+//! pub struct Note {
+//!     #[georm(id, defaultable)]
+//!     id: i32,
+//!     body: String,
+//!     #[georm(created_at)]
+//!     created_at: DateTime<Utc>,
+//!     #[georm(updated_at)]
+//!     updated_at: DateTime<Utc>,
+//! }
+//! ```
+//!
+//! The new values are read back, so the returned entity holds the timestamps
+//! actually stored.
+//!
+//! ## Optimistic Locking
+//!
+//! A field marked with `#[georm(version)]` protects entities against lost
+//! updates. `update` only writes a row whose version still matches the entity's,
+//! and increments it. When the row was modified or deleted in the meantime, it
+//! fails with a [`VersionConflict`] instead of overwriting it.
+//!
+//! ```ignore
+//! #[derive(Georm)]
+//! #[georm(table = "wiki_pages")]
+//! pub struct WikiPage {
+//!     #[georm(id)]
+//!     id: i32,
+//!     title: String,
+//!     #[georm(version)]
+//!     version: i32,
+//! }
+//!
+//! match page.update(&pool).await {
+//!     Ok(page) => { /* saved with version + 1 */ }
+//!     Err(error) if VersionConflict::matches(&error) => { /* reload and retry */ }
+//!     Err(error) => return Err(error),
+//! }
+//! ```
+//!
+//! `create_or_update` increments the version of the rows it updates without
+//! checking it. The generated `create_or_update_versioned` and `delete_versioned`
+//! methods check it like `update` does. Versions are written as-is on creation.
+//!
+//! ## Soft Deletes
+//!
+//! A field marked with `#[georm(soft_delete)]`, an optional timestamp, turns
+//! deletions into soft deletes. `delete`, `delete_by_id` and `delete_many_by_ids`
+//! set it to `now()` instead of removing the row, and the rows where it is set are
+//! left out of `find`, `find_all`, `find_many`, `find_page`, `count`, `exists` and
+//! relationship getters. A non-nullable `relation` getter still returns its
+//! related row when it is soft-deleted, since it has no way to report it missing.
+//!
+//! Writes do not look at the deletion mark: `update`, `create_or_update` and their
+//! variants write soft-deleted rows like any other and keep them deleted. Use
+//! `restore` first to bring a row back.
+//!
+//! ```ignore
+//! #[derive(Georm)]
+//! #[georm(table = "threads")]
+//! pub struct Thread {
+//!     #[georm(id)]
+//!     id: i32,
+//!     title: String,
+//!     #[georm(soft_delete)]
+//!     deleted_at: Option<DateTime<Utc>>,
+//! }
+//!
+//! thread.delete(&pool).await?;                    // Sets deleted_at
+//! let thread = Thread::find_with_deleted(&pool, &id).await?.unwrap();
+//! let thread = thread.restore(&pool).await?;      // Clears deleted_at
+//! thread.hard_delete(&pool).await?;               // Removes the row
+//! ```
 //!
 //! ## Relationships
 //!
+//!
 //! Georm supports comprehensive relationship modeling with two approaches: field-level
 //! relationships for foreign keys and struct-level relationships for reverse lookups.
 //! Each relationship method call executes a separate database query.
 //!
+//! Unlike the [`Georm`] methods, relationship getters are only partly checked at
+//! compile time. The tables and columns naming the relationship, such as
+//! `remote_id` or the link table of a many-to-many relationship, are verified
+//! against the database. The columns of the related entity are however only known
+//! to its own derive: they are selected by a query built at runtime, and the rows
+//! are decoded through the entity's `FromRow` implementation. A related entity
+//! that does not match its table is therefore reported by an error when the
+//! getter is called, not when it is compiled.
+//!
 //! ### Field-Level Relationships (Foreign Keys)
 //!
 //! Use the `relation` attribute on foreign key fields to generate lookup methods:
@@ -321,7 +718,8 @@
 //!         entity = Post,          // Related entity type
 //!         name = "posts",         // Method name (generates get_posts)
 //!         table = "posts",        // Related table name
-//!         remote_id = "author_id" // Foreign key in related table
+//!         remote_id = "author_id", // Foreign key in related table
+//!         order_by = "id DESC"    // Optional: ORDER BY clause of the getter
 //!     }, {
 //!         entity = Comment,       // Multiple relationships allowed
 //!         name = "comments",
@@ -340,6 +738,10 @@
 //! - `author.get_posts(pool).await? -> sqlx::Result<Vec<Post>>`
 //! - `author.get_comments(pool).await? -> sqlx::Result<Vec<Comment>>`
 //!
+//! Results follow the related entity's own struct-level `order_by`, if any, like
+//! its `find_all`. Setting `order_by` on the relationship overrides it for this
+//! getter. Without either, results come back in no particular order.
+//!
 //! #### Many-to-Many Relationships
 //!
 //! For many-to-many relationships, specify the link table that connects the entities:
@@ -376,7 +778,8 @@
 //!             table = "book_genres",  // Join table name
 //!             from = "book_id",       // Column referencing this entity
 //!             to = "genre_id"         // Column referencing related entity
-//!         }
+//!         },
+//!         order_by = "name"       // Optional: ORDER BY clause of the getter
 //!     }]
 //! )]
 //! pub struct Book {
@@ -508,16 +911,6 @@
 //! pub struct Invalid {
 //!     name: String,  // Missing #[georm(id)]
 //! }
-//!
-//! // ❌ Compile error: Option<T> cannot be defaultable
-//! #[derive(Georm)]
-//! #[georm(table = "invalid")]
-//! pub struct Invalid {
-//!     #[georm(id)]
-//!     id: i32,
-//!     #[georm(defaultable)]  // Error: would create Option<Option<String>>
-//!     optional_field: Option<String>,
-//! }
 //! ```
 //!
 //! ## Attribute Reference
@@ -527,6 +920,11 @@
 //! ```ignore
 //! #[georm(
 //!     table = "table_name",                   // Required: database table name
+//!     order_by = "created_at DESC, id",       // Optional: ORDER BY of find_all and getters
+//!     rename_all = "camelCase",               // Optional: case convention of column names
+//!     on_conflict = "username",               // Optional: conflict target of Default upserts
+//!     default_derive = [Debug, Clone],        // Optional: traits derived on the Default struct
+//!     patch,                                  // Optional: generate the Patch struct
 //!     one_to_one = [{ /* ... */ }],           // Optional: one-to-one relationships
 //!     one_to_many = [{ /* ... */ }],          // Optional: one-to-many relationships
 //!     many_to_many = [{ /* ... */ }]          // Optional: many-to-many relationships
@@ -539,11 +937,27 @@
 //! #[georm(id)]                               // Mark as primary key (required on at least one field)
 //! #[georm(defaultable)]                      // Mark as defaultable field (database default/auto-generated)
 //! #[georm(relation = { /* ... */ })]         // Define foreign key relationship
+//! #[georm(column = "column_name")]           // Map the field to another column
+//! #[georm(skip)]                             // Not a column, filled with Default::default()
+//! #[georm(skip, default = path::to::fn)]     // Not a column, filled with path::to::fn()
+//! #[georm(json)]                             // Stored as JSON, needs the `json` feature
+//! #[georm(sql_type = "integer")]            // Cast the column to this type when read
+//! #[georm(generated)]                        // Computed by the database, never written
+//! #[georm(immutable)]                        // Written on creation only
+//! #[georm(created_at)]                       // Set to now() on creation
+//! #[georm(updated_at)]                       // Set to now() on every write
+//! #[georm(version)]                          // Optimistic locking version
+//! #[georm(soft_delete)]                      // Set to now() by delete, hides the row
 //! ```
 //!
 //! ## Performance Characteristics
 //!
-//! - **Zero runtime overhead**: All SQL is generated at compile time
+//! - **Compile-time queries**: The SQL of the [`Georm`] methods is written and verified at compile time
+//! - **Runtime relationship queries**: Only the related entity's own derive knows its
+//!   columns, so relationship getters build their query from them at runtime, once,
+//!   and decode rows through the entity's `FromRow`. The tables and columns of the
+//!   relationship itself are still verified at compile time, but the related
+//!   entity's columns and the decoding of its rows are only checked at runtime
 //! - **No eager loading**: Each relationship method executes a separate query
 //! - **Prepared statements**: All queries use parameter binding for optimal performance
 //! - **Database round-trips**: CRUD operations use RETURNING clause to minimize round-trips
@@ -577,13 +991,29 @@
 //! Georm automatically generates:
 //! - `sqlx::FromRow` implementation (no need to derive manually)
 //! - Composite ID structs for multi-field primary keys
-//! - Defaultable companion structs for entities with defaultable fields
+//! - Defaultable companion structs for entities with defaultable fields, their
+//!   builders and conversions
+//! - Patch companion structs for partial updates, with the `patch` attribute
 //! - Relationship methods for accessing related entities
 //! - All CRUD operations with proper PostgreSQL optimizations
 
 pub use georm_macros::Georm;
 
+#[doc(hidden)]
+pub use futures_core;
+
 mod georm;
 pub use georm::Georm;
 mod defaultable;
 pub use defaultable::Defaultable;
+mod default_or;
+pub use default_or::DefaultOr;
+mod transaction;
+pub use transaction::transaction;
+#[doc(hidden)]
+#[cfg(feature = "json")]
+pub mod json;
+mod pagination;
+pub use pagination::{Cursor, InvalidCursor, Page, PageRequest};
+mod version;
+pub use version::VersionConflict;
@@ -0,0 +1,185 @@
+/// Parameters of a keyset pagination query.
+///
+/// Pages are always ordered by primary key. The first page is requested with
+/// [`PageRequest::first`], and the following ones by passing the
+/// [`next_cursor`](Page::next_cursor) of the previous page to [`PageRequest::after`].
+///
+/// # Examples
+/// ```ignore
+/// let page = User::find_page(&pool, PageRequest::first(20)).await?;
+/// if let Some(cursor) = page.next_cursor {
+///     let next_page = User::find_page(&pool, PageRequest::after(cursor, 20)).await?;
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRequest {
+    /// Maximum number of entities in the page. A limit of 0 is treated as 1, so
+    /// that walking through pages always makes progress.
+    pub limit: u32,
+    /// Cursor of the last entity of the previous page, `None` for the first page.
+    pub after: Option<Cursor>,
+}
+
+impl PageRequest {
+    /// Request the first page, holding at most `limit` entities.
+    #[must_use]
+    pub const fn first(limit: u32) -> Self {
+        Self { limit, after: None }
+    }
+
+    /// Request the page following `cursor`, holding at most `limit` entities.
+    #[must_use]
+    pub const fn after(cursor: Cursor, limit: u32) -> Self {
+        Self {
+            limit,
+            after: Some(cursor),
+        }
+    }
+}
+
+/// A page of entities returned by [`Georm::find_page`](crate::Georm::find_page).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    /// Entities of the page, in primary key order.
+    pub items: Vec<T>,
+    /// Cursor to request the next page with, `None` if this is the last page.
+    pub next_cursor: Option<Cursor>,
+}
+
+impl<T> Page<T> {
+    /// Build a page from entities of `table` fetched with one more than `limit`,
+    /// each along with its primary key as a JSON object. Used by the code generated
+    /// by `#[derive(Georm)]`.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn from_items(mut items: Vec<(T, String)>, limit: u32, table: &str) -> Self {
+        let limit = limit as usize;
+        let next_cursor = if items.len() > limit {
+            items.truncate(limit);
+            items.last().map(|(_, key)| Cursor {
+                table: table.to_string(),
+                key: key.clone(),
+            })
+        } else {
+            None
+        };
+        let items = items.into_iter().map(|(item, _)| item).collect();
+        Self { items, next_cursor }
+    }
+}
+
+/// Opaque position of an entity in primary key order.
+///
+/// Cursors are meant to be handed to clients as-is, for instance in a REST API
+/// response, and sent back to request the next page. They convert to and from
+/// strings through [`Display`](std::fmt::Display) and [`FromStr`](std::str::FromStr),
+/// and are serialized as such with `serde` when the `serde` feature is enabled.
+///
+/// A cursor records the table of the entity it comes from, and can only be used
+/// to paginate that table: passing it to the `find_page` of an entity stored in
+/// another table fails with an [`InvalidCursor`] error, even when both have
+/// primary keys with the same column names.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cursor {
+    table: String,
+    key: String,
+}
+
+impl Cursor {
+    /// Primary key of the entity as a JSON object. Used by the code generated by
+    /// `#[derive(Georm)]`.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn as_json(&self) -> &str {
+        &self.key
+    }
+
+    /// Check that the cursor comes from an entity stored in `table`. Used by the
+    /// code generated by `#[derive(Georm)]`.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn is_for(&self, table: &str) -> bool {
+        self.table == table
+    }
+}
+
+// The table and the key are separated by a NUL byte, which neither a PostgreSQL
+// identifier nor a JSON object can contain.
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.table
+            .bytes()
+            .chain(std::iter::once(0))
+            .chain(self.key.bytes())
+            .try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl std::str::FromStr for Cursor {
+    type Err = InvalidCursor;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // An odd length leaves a last chunk of a single digit, which is rejected
+        let bytes = s
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| {
+                std::str::from_utf8(pair)
+                    .ok()
+                    .filter(|hex| hex.len() == 2)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or(InvalidCursor)?;
+        let token = String::from_utf8(bytes).map_err(|_| InvalidCursor)?;
+        let (table, key) = token.split_once('\0').ok_or(InvalidCursor)?;
+        if table.is_empty() || !key.starts_with('{') || !key.ends_with('}') {
+            return Err(InvalidCursor);
+        }
+        Ok(Self {
+            table: table.to_string(),
+            key: key.to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Cursor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Cursor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let token = String::deserialize(deserializer)?;
+        token.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Error returned when parsing a string that is not a valid [`Cursor`], and by
+/// `find_page` when given the cursor of an entity stored in another table, as a
+/// [`sqlx::Error::Decode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCursor;
+
+impl std::fmt::Display for InvalidCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("invalid pagination cursor")
+    }
+}
+
+impl std::error::Error for InvalidCursor {}
+
+impl From<InvalidCursor> for sqlx::Error {
+    fn from(error: InvalidCursor) -> Self {
+        Self::Decode(Box::new(error))
+    }
+}
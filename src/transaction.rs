@@ -0,0 +1,72 @@
+/// Run a closure inside a database transaction.
+///
+/// This function begins a transaction on `acquire`, hands the underlying connection
+/// to `f`, then commits if `f` returns `Ok` or rolls back if it returns `Err`. The
+/// connection given to the closure can be passed to any Georm method with
+/// `&mut *conn`, so every operation performed inside the closure belongs to the same
+/// unit of work.
+///
+/// ## Nesting
+///
+/// `acquire` can be a pool, a connection, or a transaction. When a transaction is
+/// already open on it, the call creates a `SAVEPOINT` instead of a new transaction:
+/// an `Err` returned by the inner closure only rolls back to that savepoint, and the
+/// outer transaction decides whether the rest is committed. Helpers can therefore
+/// wrap their own writes with `transaction` without knowing whether their caller
+/// already opened one.
+///
+/// # Parameters
+/// - `acquire` - A pool, a connection, or a transaction to run the closure on
+/// - `f` - An async closure receiving the transaction's connection
+///
+/// # Returns
+/// - `Ok(T)` - The closure's value, once the transaction or savepoint is committed
+/// - `Err(E)` - The closure's error after rolling back, or any error raised while
+///   beginning or committing
+///
+/// # Examples
+/// ```ignore
+/// use georm::{Defaultable, Georm};
+///
+/// let (user, profile) = georm::transaction(&pool, async |conn| {
+///     let user = new_user.create(&mut *conn).await?;
+///     let profile = Profile { user_id: user.id, ..new_profile };
+///     let profile = profile.create(&mut *conn).await?;
+///     Ok::<_, sqlx::Error>((user, profile))
+/// })
+/// .await?;
+///
+/// // Composing helpers: the inner call becomes a savepoint
+/// async fn follow(conn: &mut sqlx::PgConnection, follower: i32, followed: i32) -> sqlx::Result<()> {
+///     georm::transaction(conn, async |conn| {
+///         FollowerDefault { id: None, follower, followed }.create(&mut *conn).await?;
+///         Ok(())
+///     })
+///     .await
+/// }
+/// ```
+///
+/// # Errors
+/// Returns the closure's error when it fails, even if rolling back fails too, and
+/// converts any `sqlx::Error` raised while beginning or committing the transaction
+/// into `E`.
+pub async fn transaction<'a, A, F, T, E>(acquire: A, f: F) -> Result<T, E>
+where
+    A: sqlx::Acquire<'a, Database = sqlx::Postgres>,
+    F: AsyncFnOnce(&mut sqlx::PgConnection) -> Result<T, E>,
+    E: From<sqlx::Error>,
+{
+    let mut tx = acquire.begin().await?;
+    match f(&mut tx).await {
+        Ok(value) => {
+            tx.commit().await?;
+            Ok(value)
+        }
+        Err(error) => {
+            // A failed rollback must not hide the closure's error, and the
+            // transaction is rolled back anyway when dropped
+            let _ = tx.rollback().await;
+            Err(error)
+        }
+    }
+}
@@ -0,0 +1,76 @@
+/// Error returned when a write on an entity with a `#[georm(version)]` field
+/// finds no row at the expected version, meaning another process modified or
+/// deleted it since it was read.
+///
+/// It is reported as [`sqlx::Error::Database`], from which it can be recovered
+/// with [`VersionConflict::matches`] or by downcasting.
+///
+/// # Examples
+/// ```ignore
+/// match document.update(&pool).await {
+///     Ok(document) => { /* saved */ }
+///     Err(error) if VersionConflict::matches(&error) => { /* reload and retry */ }
+///     Err(error) => return Err(error),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionConflict {
+    table: &'static str,
+    message: String,
+}
+
+impl VersionConflict {
+    /// Create a conflict for a row of `table`. Used by the code generated by
+    /// `#[derive(Georm)]`.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn new(table: &'static str) -> Self {
+        Self {
+            table,
+            message: format!("version conflict on a row of table {table}"),
+        }
+    }
+
+    /// Check whether `error` is a version conflict.
+    #[must_use]
+    pub fn matches(error: &sqlx::Error) -> bool {
+        match error {
+            sqlx::Error::Database(error) => error.try_downcast_ref::<Self>().is_some(),
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for VersionConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for VersionConflict {}
+
+impl sqlx::error::DatabaseError for VersionConflict {
+    fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+        self
+    }
+
+    fn table(&self) -> Option<&str> {
+        Some(self.table)
+    }
+
+    fn kind(&self) -> sqlx::error::ErrorKind {
+        sqlx::error::ErrorKind::Other
+    }
+}
@@ -1,7 +1,26 @@
-use georm::Georm;
+use georm::{Defaultable, Georm, PageRequest};
 
 mod models;
-use models::{UserRole, UserRoleId};
+use models::{UserRole, UserRoleDefault, UserRoleId};
+
+#[derive(Georm, Debug, PartialEq, Eq)]
+#[georm(table = "order_lines")]
+struct OrderLine {
+    #[georm(id)]
+    order_id: i32,
+    #[georm(id, defaultable)]
+    line_number: i32,
+    product: String,
+}
+
+/// Create `entity` through the `Defaultable` impl whose `Id` is the entity's
+/// composite key struct.
+async fn create_defaultable<D, T>(entity: D, pool: &sqlx::PgPool) -> sqlx::Result<T>
+where
+    D: Defaultable<UserRoleId, T>,
+{
+    entity.create(pool).await
+}
 
 #[sqlx::test(fixtures("composite_key"))]
 async fn composite_key_find(pool: sqlx::PgPool) -> sqlx::Result<()> {
@@ -110,3 +129,172 @@ async fn composite_key_update(pool: sqlx::PgPool) -> sqlx::Result<()> {
     assert_eq!(1, updated.role_id);
     Ok(())
 }
+
+#[sqlx::test(fixtures("composite_key"))]
+async fn composite_key_create_many(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let now: chrono::DateTime<chrono::Utc> = chrono::Local::now().into();
+    let user_roles = vec![
+        UserRole {
+            user_id: 10,
+            role_id: 5,
+            assigned_at: now,
+        },
+        UserRole {
+            user_id: 10,
+            role_id: 6,
+            assigned_at: now,
+        },
+    ];
+    let created = UserRole::create_many(&user_roles, &pool).await?;
+    assert_eq!(2, created.len());
+    assert_eq!((10, 5), (created[0].user_id, created[0].role_id));
+    assert_eq!((10, 6), (created[1].user_id, created[1].role_id));
+    assert_eq!(6, UserRole::find_all(&pool).await?.len());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("composite_key"))]
+async fn composite_key_create_or_update_many(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let now: chrono::DateTime<chrono::Utc> = chrono::Local::now().into();
+    let user_roles = vec![
+        UserRole {
+            user_id: 5,
+            role_id: 2,
+            assigned_at: now,
+        },
+        UserRole {
+            user_id: 1,
+            role_id: 1,
+            assigned_at: now,
+        },
+    ];
+    let upserted = UserRole::create_or_update_many(&user_roles, &pool).await?;
+    assert_eq!(2, upserted.len());
+    assert_eq!((5, 2), (upserted[0].user_id, upserted[0].role_id));
+    assert_eq!((1, 1), (upserted[1].user_id, upserted[1].role_id));
+    assert_eq!(
+        now.timestamp_millis(),
+        upserted[1].assigned_at.timestamp_millis()
+    );
+    assert_eq!(5, UserRole::find_all(&pool).await?.len());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("composite_key"))]
+async fn composite_key_find_many(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let ids = [
+        UserRoleId {
+            user_id: 1,
+            role_id: 2,
+        },
+        UserRoleId {
+            user_id: 3,
+            role_id: 3,
+        },
+        UserRoleId {
+            user_id: 2,
+            role_id: 2,
+        },
+    ];
+    let mut user_roles = UserRole::find_many(&pool, &ids).await?;
+    user_roles.sort_by_key(|user_role| (user_role.user_id, user_role.role_id));
+    assert_eq!(2, user_roles.len());
+    assert_eq!((1, 2), (user_roles[0].user_id, user_roles[0].role_id));
+    assert_eq!((3, 3), (user_roles[1].user_id, user_roles[1].role_id));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("composite_key"))]
+async fn composite_key_delete_many_by_ids(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let ids = [
+        UserRoleId {
+            user_id: 1,
+            role_id: 1,
+        },
+        UserRoleId {
+            user_id: 2,
+            role_id: 1,
+        },
+        UserRoleId {
+            user_id: 1,
+            role_id: 3,
+        },
+    ];
+    let rows_affected = UserRole::delete_many_by_ids(&pool, &ids).await?;
+    assert_eq!(2, rows_affected);
+    assert_eq!(2, UserRole::find_all(&pool).await?.len());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("composite_key"))]
+async fn composite_key_find_page(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let mut keys = Vec::new();
+    let mut request = PageRequest::first(3);
+    loop {
+        let page = UserRole::find_page(&pool, request).await?;
+        keys.extend(page.items.iter().map(|ur| (ur.user_id, ur.role_id)));
+        match page.next_cursor {
+            Some(cursor) => request = PageRequest::after(cursor, 3),
+            None => break,
+        }
+    }
+    assert_eq!(vec![(1, 1), (1, 2), (2, 1), (3, 3)], keys);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("composite_key"))]
+async fn composite_key_count_and_exists(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    assert_eq!(4, UserRole::count(&pool).await?);
+    let existing = UserRoleId {
+        user_id: 1,
+        role_id: 2,
+    };
+    let missing = UserRoleId {
+        user_id: 2,
+        role_id: 2,
+    };
+    assert!(UserRole::exists(&pool, &existing).await?);
+    assert!(!UserRole::exists(&pool, &missing).await?);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn composite_key_defaultable_create(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let created = create_defaultable(
+        UserRoleDefault {
+            user_id: 4,
+            role_id: 1,
+            assigned_at: None,
+        },
+        &pool,
+    )
+    .await?;
+    let id = UserRoleId {
+        user_id: 4,
+        role_id: 1,
+    };
+    assert_eq!(UserRole::find(&pool, &id).await?, Some(created));
+    Ok(())
+}
+
+#[sqlx::test]
+async fn composite_key_with_defaultable_id_column(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let line = |order_id: i32, line_number: Option<i32>, product: &str| OrderLineDefault {
+        order_id,
+        line_number,
+        product: product.into(),
+    };
+    let first = line(1, None, "Keyboard").create(&pool).await?;
+    let second = line(1, None, "Mouse").create(&pool).await?;
+    let explicit = line(2, Some(10), "Screen").create(&pool).await?;
+    assert_eq!((1, 1), (first.order_id, first.line_number));
+    assert_eq!((1, 2), (second.order_id, second.line_number));
+    assert_eq!((2, 10), (explicit.order_id, explicit.line_number));
+
+    let id = OrderLineId {
+        order_id: 1,
+        line_number: 2,
+    };
+    assert_eq!(Some(second), OrderLine::find(&pool, &id).await?);
+    Ok(())
+}
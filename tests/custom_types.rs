@@ -0,0 +1,181 @@
+use georm::{Defaultable, Georm, PageRequest};
+
+#[derive(sqlx::Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[sqlx(type_name = "ticket_status", rename_all = "snake_case")]
+enum TicketStatus {
+    Open,
+    InProgress,
+    Closed,
+}
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "tickets")]
+struct Ticket {
+    #[georm(id, defaultable)]
+    id: i32,
+    title: String,
+    #[georm(defaultable)]
+    status: TicketStatus,
+    previous_status: Option<TicketStatus>,
+    #[georm(sql_type = "integer")]
+    priority: i32,
+}
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "notes")]
+struct NoteWithLongTypes {
+    #[georm(id)]
+    id: i32,
+    body: String,
+    created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    updated_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+}
+
+#[sqlx::test(fixtures("custom_types"))]
+async fn custom_types_are_decoded(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let first = Ticket::find(&pool, &1).await?.unwrap();
+    assert_eq!(TicketStatus::InProgress, first.status);
+    assert_eq!(Some(TicketStatus::Open), first.previous_status);
+    assert_eq!(1, first.priority);
+
+    let second = Ticket::find(&pool, &2).await?.unwrap();
+    assert_eq!(None, second.previous_status);
+    assert_eq!(5, second.priority);
+
+    assert_eq!(2, Ticket::find_all(&pool).await?.len());
+    assert_eq!(vec![first.clone()], Ticket::find_many(&pool, &[1]).await?);
+    let page = Ticket::find_page(&pool, PageRequest::first(5)).await?;
+    assert_eq!(vec![first, second], page.items);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("custom_types"))]
+async fn custom_types_are_encoded(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let ticket = Ticket {
+        id: 3,
+        title: "Slow search".into(),
+        status: TicketStatus::Open,
+        previous_status: None,
+        priority: 3,
+    };
+    let created = ticket.create(&pool).await?;
+    assert_eq!(ticket, created);
+
+    let mut updated = created.clone();
+    updated.previous_status = Some(updated.status);
+    updated.status = TicketStatus::Closed;
+    let updated = updated.update(&pool).await?;
+    assert_eq!(TicketStatus::Closed, updated.status);
+    assert_eq!(Some(TicketStatus::Open), updated.previous_status);
+
+    let upserted = Ticket {
+        id: 1,
+        title: "Login fails".into(),
+        status: TicketStatus::Closed,
+        previous_status: None,
+        priority: 3,
+    }
+    .create_or_update(&pool)
+    .await?;
+    assert_eq!(TicketStatus::Closed, upserted.status);
+
+    let status: String = sqlx::query_scalar("SELECT status::text FROM tickets WHERE id = 1")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!("closed", status);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("custom_types"))]
+async fn custom_types_work_in_bulk_operations(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let tickets = [
+        Ticket {
+            id: 3,
+            title: "a".into(),
+            status: TicketStatus::Open,
+            previous_status: None,
+            priority: 3,
+        },
+        Ticket {
+            id: 4,
+            title: "b".into(),
+            status: TicketStatus::InProgress,
+            previous_status: Some(TicketStatus::Open),
+            priority: 4,
+        },
+    ];
+    assert_eq!(
+        tickets.to_vec(),
+        Ticket::create_many(&tickets, &pool).await?
+    );
+
+    let tickets = [
+        Ticket {
+            id: 4,
+            title: "b".into(),
+            status: TicketStatus::Closed,
+            previous_status: Some(TicketStatus::InProgress),
+            priority: 4,
+        },
+        Ticket {
+            id: 5,
+            title: "c".into(),
+            status: TicketStatus::Open,
+            previous_status: None,
+            priority: 2,
+        },
+    ];
+    assert_eq!(
+        tickets.to_vec(),
+        Ticket::create_or_update_many(&tickets, &pool).await?
+    );
+    Ok(())
+}
+
+#[sqlx::test]
+async fn custom_types_work_in_defaultable_create(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let created = TicketDefault {
+        id: None,
+        title: "Crash on start".into(),
+        status: None,
+        previous_status: None,
+        priority: 2,
+    }
+    .create(&pool)
+    .await?;
+    assert_eq!(TicketStatus::Open, created.status);
+    assert_eq!(2, created.priority);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("custom_types"))]
+async fn domain_constraints_are_enforced(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let invalid = Ticket {
+        id: 3,
+        title: "Too urgent".into(),
+        status: TicketStatus::Open,
+        previous_status: None,
+        priority: 6,
+    };
+    assert!(invalid.create(&pool).await.is_err());
+    assert_eq!(2, Ticket::count(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("timestamps"))]
+async fn types_too_long_to_override_are_inferred(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let first = NoteWithLongTypes::find(&pool, &1).await?.unwrap();
+    assert_eq!("2020-01-01 00:00:00 UTC", first.created_at.to_string());
+    assert_eq!(
+        Some("2020-01-02 00:00:00 UTC".into()),
+        first.updated_at.map(|date| date.to_string())
+    );
+
+    let note = NoteWithLongTypes {
+        id: 3,
+        body: "Third".into(),
+        ..first
+    };
+    assert_eq!(note, note.create(&pool).await?);
+    Ok(())
+}
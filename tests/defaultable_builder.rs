@@ -0,0 +1,82 @@
+use georm::{DefaultOr, Defaultable, Georm};
+
+mod models;
+use models::{LegacyOrder, UserRole};
+
+#[derive(Georm, Debug, PartialEq, Eq)]
+#[georm(table = "bookmarks")]
+struct Bookmark {
+    #[georm(id, defaultable)]
+    id: i32,
+    url: String,
+    #[georm(defaultable)]
+    folder: Option<String>,
+}
+
+#[test]
+fn builder_leaves_defaultable_fields_unset() {
+    let bookmark = Bookmark::builder()
+        .url("https://example.com".into())
+        .build();
+    assert_eq!(None, bookmark.id);
+    assert_eq!("https://example.com", bookmark.url);
+    assert_eq!(DefaultOr::Default, bookmark.folder);
+}
+
+#[test]
+fn builder_sets_defaultable_fields_in_any_order() {
+    let bookmark = Bookmark::builder()
+        .folder(None)
+        .url("https://example.com".into())
+        .id(3)
+        .build();
+    assert_eq!(Some(3), bookmark.id);
+    assert_eq!(DefaultOr::Value(None), bookmark.folder);
+}
+
+#[sqlx::test]
+async fn builder_feeds_defaultable_create(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let defaulted = Bookmark::builder()
+        .url("https://example.com".into())
+        .build()
+        .create(&pool)
+        .await?;
+    assert_eq!(Some("unsorted".to_string()), defaulted.folder);
+
+    let explicit = Bookmark::builder()
+        .url("https://example.org".into())
+        .folder(Some("reading".into()))
+        .build()
+        .create(&pool)
+        .await?;
+    assert_eq!(Some(explicit), Bookmark::find(&pool, &2).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn builder_handles_renamed_and_raw_fields(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let order = LegacyOrder::builder()
+        .author_id(Some(1))
+        .kind("online".into())
+        .r#order(5)
+        .customer_name("Dave".into())
+        .build()
+        .create(&pool)
+        .await?;
+    assert_eq!("Dave", order.customer_name);
+    assert_eq!(5, order.r#order);
+    assert_eq!(Some(1), order.author_id);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn builder_works_with_composite_keys(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let user_role = UserRole::builder()
+        .role_id(2)
+        .user_id(7)
+        .build()
+        .create(&pool)
+        .await?;
+    assert_eq!((7, 2), (user_role.user_id, user_role.role_id));
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use georm::{DefaultOr, Defaultable, Georm};
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "bookmarks", default_derive = [Debug, Clone, PartialEq])]
+struct Bookmark {
+    #[georm(id, defaultable)]
+    id: i32,
+    url: String,
+    #[georm(defaultable)]
+    folder: Option<String>,
+}
+
+#[derive(sqlx::Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[sqlx(type_name = "ticket_status", rename_all = "snake_case")]
+enum TicketStatus {
+    Open,
+    InProgress,
+    Closed,
+}
+
+// `TicketStatus` does not implement `Default`, neither does `TicketDefault`
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "tickets")]
+struct Ticket {
+    #[georm(id, defaultable)]
+    id: i32,
+    title: String,
+    status: TicketStatus,
+    previous_status: Option<TicketStatus>,
+    priority: i32,
+}
+
+#[test]
+fn from_entity_provides_every_defaultable_field() {
+    let bookmark = Bookmark {
+        id: 4,
+        url: "https://example.com".into(),
+        folder: None,
+    };
+    assert_eq!(
+        BookmarkDefault {
+            id: Some(4),
+            url: "https://example.com".into(),
+            folder: DefaultOr::Value(None),
+        },
+        bookmark.into()
+    );
+}
+
+#[test]
+fn default_leaves_defaultable_fields_unset() {
+    assert_eq!(
+        BookmarkDefault {
+            id: None,
+            url: String::new(),
+            folder: DefaultOr::Default,
+        },
+        BookmarkDefault::default()
+    );
+}
+
+#[test]
+fn derives_are_passed_through() {
+    let bookmark = BookmarkDefault {
+        url: "https://example.com".into(),
+        ..Default::default()
+    };
+    assert_eq!(bookmark, bookmark.clone());
+    assert!(format!("{bookmark:?}").starts_with("BookmarkDefault"));
+}
+
+// `DefaultOr` only implements serde's traits with the `serde` feature
+#[cfg(feature = "serde")]
+#[test]
+fn serde_derives_are_passed_through() {
+    #[derive(Georm)]
+    #[georm(table = "bookmarks", default_derive = [Debug, PartialEq, serde::Serialize, serde::Deserialize])]
+    struct SerializableBookmark {
+        #[georm(id, defaultable)]
+        id: i32,
+        url: String,
+        #[georm(defaultable)]
+        folder: Option<String>,
+    }
+
+    let bookmark = SerializableBookmarkDefault {
+        url: "https://example.com".into(),
+        ..Default::default()
+    };
+    let json = serde_json::to_value(&bookmark).unwrap();
+    assert_eq!("Default", json["folder"]);
+    assert_eq!(bookmark, serde_json::from_value(json).unwrap());
+}
+
+#[sqlx::test]
+async fn entities_can_be_copied_through_their_default(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let original = BookmarkDefault {
+        url: "https://example.com".into(),
+        ..Default::default()
+    }
+    .create(&pool)
+    .await?;
+    let mut copy = BookmarkDefault::from(original.clone());
+    copy.id = None;
+    let copy = copy.create(&pool).await?;
+    assert_eq!(2, copy.id);
+    assert_eq!(original.folder, copy.folder);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn required_fields_without_default_are_converted(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let ticket = Ticket {
+        id: 1,
+        title: "Login fails".into(),
+        status: TicketStatus::Closed,
+        previous_status: Some(TicketStatus::InProgress),
+        priority: 2,
+    };
+    let created = TicketDefault::from(ticket.clone()).create(&pool).await?;
+    assert_eq!(ticket, created);
+    Ok(())
+}
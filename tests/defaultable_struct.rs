@@ -31,6 +31,48 @@ struct MultiDefaultable {
     pub biography_id: Option<i32>,
 }
 
+// Test struct with a nullable column having a database default
+#[derive(Georm, Debug)]
+#[georm(table = "bookmarks")]
+struct Bookmark {
+    #[georm(id, defaultable)]
+    pub id: i32,
+    pub url: String,
+    #[georm(defaultable)]
+    pub folder: Option<String>, // DEFAULT 'unsorted'
+}
+
+// Test struct where every column has a database default
+#[derive(Georm, Debug)]
+#[georm(table = "page_views")]
+struct PageView {
+    #[georm(id, defaultable)]
+    pub id: i32,
+    #[georm(defaultable)]
+    pub viewed_at: chrono::DateTime<chrono::Utc>,
+}
+
+// Test struct with more defaultable fields than get a checked query each
+#[derive(Georm, Debug)]
+#[georm(table = "user_settings")]
+struct UserSettings {
+    #[georm(id, defaultable)]
+    pub id: i32,
+    pub username: String,
+    #[georm(defaultable)]
+    pub theme: String,
+    #[georm(defaultable)]
+    pub language: String,
+    #[georm(defaultable)]
+    pub notifications: bool,
+    #[georm(defaultable)]
+    pub items_per_page: i32,
+    #[georm(defaultable)]
+    pub timezone: Option<String>,
+    #[georm(updated_at)]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
 #[test]
 fn defaultable_struct_should_exist() {
     // This test will compile only if TestAuthorDefault struct exists
@@ -60,6 +102,16 @@ fn defaultable_fields_can_be_none() {
     };
 }
 
+#[test]
+fn nullable_defaultable_fields_are_tri_state() {
+    let _bookmark_default = BookmarkDefault {
+        id: None,
+        url: "https://example.com".to_string(),
+        folder: georm::DefaultOr::Value(None), // Should be DefaultOr<Option<String>>
+    };
+    assert!(georm::DefaultOr::<Option<String>>::default().is_default());
+}
+
 #[test]
 fn field_visibility_is_preserved() {
     let _author_default = TestAuthorDefault {
@@ -297,6 +349,101 @@ mod defaultable_tests {
         }
     }
 
+    #[sqlx::test]
+    async fn test_nullable_defaultable_field_states(pool: PgPool) {
+        // Test the three states of a nullable defaultable field
+        let bookmark = |folder: georm::DefaultOr<Option<String>>| BookmarkDefault {
+            id: None,
+            url: "https://example.com".to_string(),
+            folder,
+        };
+
+        let defaulted = bookmark(georm::DefaultOr::Default)
+            .create(&pool)
+            .await
+            .unwrap();
+        assert_eq!(defaulted.folder, Some("unsorted".to_string()));
+
+        let null = bookmark(georm::DefaultOr::Value(None))
+            .create(&pool)
+            .await
+            .unwrap();
+        assert_eq!(null.folder, None);
+
+        let explicit = bookmark(Some("reading".to_string()).into())
+            .create(&pool)
+            .await
+            .unwrap();
+        assert_eq!(explicit.folder, Some("reading".to_string()));
+    }
+
+    #[sqlx::test]
+    async fn test_create_with_every_field_defaulted(pool: PgPool) {
+        // Test creating an entity without any column, using DEFAULT VALUES
+        let first = PageViewDefault {
+            id: None,
+            viewed_at: None,
+        }
+        .create(&pool)
+        .await
+        .unwrap();
+        let viewed_at = chrono::DateTime::UNIX_EPOCH;
+        let second = PageViewDefault {
+            id: None,
+            viewed_at: Some(viewed_at),
+        }
+        .create(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(first.id + 1, second.id);
+        assert!(first.viewed_at > viewed_at);
+        assert_eq!(second.viewed_at, viewed_at);
+    }
+
+    #[sqlx::test]
+    async fn test_create_with_many_defaultable_fields(pool: PgPool) {
+        // Test the statement built at runtime from the fields provided
+        let defaulted = UserSettingsDefault {
+            id: None,
+            username: "alice".to_string(),
+            theme: None,
+            language: None,
+            notifications: None,
+            items_per_page: None,
+            timezone: georm::DefaultOr::Default,
+        }
+        .create(&pool)
+        .await
+        .unwrap();
+        assert_eq!(defaulted.username, "alice");
+        assert_eq!(defaulted.theme, "light");
+        assert_eq!(defaulted.language, "en");
+        assert!(defaulted.notifications);
+        assert_eq!(defaulted.items_per_page, 20);
+        assert_eq!(defaulted.timezone, Some("UTC".to_string()));
+
+        let provided = UserSettingsDefault {
+            id: Some(10),
+            username: "bob".to_string(),
+            theme: Some("dark".to_string()),
+            language: None,
+            notifications: Some(false),
+            items_per_page: None,
+            timezone: georm::DefaultOr::Value(None),
+        }
+        .create(&pool)
+        .await
+        .unwrap();
+        assert_eq!(provided.id, 10);
+        assert_eq!(provided.theme, "dark");
+        assert_eq!(provided.language, "en");
+        assert!(!provided.notifications);
+        assert_eq!(provided.items_per_page, 20);
+        assert_eq!(provided.timezone, None);
+        assert!(provided.updated_at >= defaulted.updated_at);
+    }
+
     mod sql_validation_tests {
         use super::*;
 
@@ -0,0 +1,219 @@
+use georm::{DefaultOr, Defaultable, Georm};
+
+mod models;
+use models::{UserRole, UserRoleDefault, UserRoleId};
+
+#[derive(Georm, Debug, PartialEq, Eq)]
+#[georm(table = "accounts", on_conflict = "username")]
+struct Account {
+    #[georm(id, defaultable)]
+    id: i32,
+    username: String,
+    #[georm(defaultable)]
+    email: Option<String>,
+    #[georm(defaultable)]
+    login_count: i32,
+}
+
+#[derive(Georm, Debug, PartialEq, Eq)]
+#[georm(table = "user_settings", on_conflict = "username")]
+struct UserSettings {
+    #[georm(id, defaultable)]
+    id: i32,
+    username: String,
+    #[georm(defaultable)]
+    theme: String,
+    #[georm(defaultable)]
+    language: String,
+    #[georm(defaultable)]
+    notifications: bool,
+    #[georm(defaultable)]
+    items_per_page: i32,
+    #[georm(defaultable)]
+    timezone: Option<String>,
+    #[georm(updated_at)]
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Georm, Debug, PartialEq, Eq)]
+#[georm(table = "accounts")]
+struct AccountById {
+    #[georm(id, defaultable)]
+    id: i32,
+    username: String,
+    #[georm(defaultable)]
+    email: Option<String>,
+    #[georm(defaultable)]
+    login_count: i32,
+}
+
+#[sqlx::test(fixtures("accounts"))]
+async fn upsert_inserts_new_natural_keys(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let inserted = AccountDefault {
+        id: None,
+        username: "carol".into(),
+        email: DefaultOr::Default,
+        login_count: None,
+    }
+    .create_or_update(&pool)
+    .await?;
+    assert_eq!(
+        Account {
+            id: 3,
+            username: "carol".into(),
+            email: None,
+            login_count: 0,
+        },
+        inserted
+    );
+    assert_eq!(3, Account::count(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("accounts"))]
+async fn upsert_only_updates_provided_columns(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let updated = AccountDefault {
+        id: None,
+        username: "alice".into(),
+        email: DefaultOr::Value(None),
+        login_count: None,
+    }
+    .create_or_update(&pool)
+    .await?;
+    assert_eq!(
+        Account {
+            id: 1,
+            username: "alice".into(),
+            email: None,
+            login_count: 3,
+        },
+        updated
+    );
+
+    let updated = Account::builder()
+        .username("bob".into())
+        .login_count(2)
+        .build()
+        .create_or_update(&pool)
+        .await?;
+    assert_eq!(
+        Account {
+            id: 2,
+            username: "bob".into(),
+            email: None,
+            login_count: 2,
+        },
+        updated
+    );
+    assert_eq!(2, Account::count(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("accounts"))]
+async fn upsert_conflicts_on_primary_key_by_default(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let updated = AccountByIdDefault {
+        id: Some(2),
+        username: "robert".into(),
+        email: DefaultOr::Default,
+        login_count: None,
+    }
+    .create_or_update(&pool)
+    .await?;
+    assert_eq!("robert", updated.username);
+    assert_eq!(1, updated.login_count);
+
+    let error = AccountByIdDefault {
+        id: None,
+        username: "alice".into(),
+        email: DefaultOr::Default,
+        login_count: None,
+    }
+    .create_or_update(&pool)
+    .await;
+    assert!(error.is_err());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("accounts"))]
+async fn upsert_only_conflicts_on_its_target(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    // Account conflicts on the username, a new one with an existing primary key
+    // violates the primary key instead of updating its row
+    let error = AccountDefault {
+        id: Some(1),
+        username: "carol".into(),
+        email: DefaultOr::Default,
+        login_count: None,
+    }
+    .create_or_update(&pool)
+    .await
+    .unwrap_err();
+    let sqlx::Error::Database(error) = error else {
+        panic!("expected a database error");
+    };
+    assert!(error.is_unique_violation());
+    assert_eq!("alice", Account::find(&pool, &1).await?.unwrap().username);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("user_settings"))]
+async fn upsert_with_many_defaultable_fields(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let updated = UserSettingsDefault {
+        id: None,
+        username: "alice".into(),
+        theme: Some("light".into()),
+        language: None,
+        notifications: None,
+        items_per_page: Some(10),
+        timezone: DefaultOr::Value(None),
+    }
+    .create_or_update(&pool)
+    .await?;
+    let expected = UserSettings {
+        id: 1,
+        username: "alice".into(),
+        theme: "light".into(),
+        language: "fr".into(),
+        notifications: false,
+        items_per_page: 10,
+        timezone: None,
+        updated_at: updated.updated_at,
+    };
+    assert_eq!(expected, updated);
+
+    let inserted = UserSettingsDefault {
+        id: None,
+        username: "bob".into(),
+        theme: None,
+        language: Some("de".into()),
+        notifications: None,
+        items_per_page: None,
+        timezone: DefaultOr::Default,
+    }
+    .create_or_update(&pool)
+    .await?;
+    assert_eq!("light", inserted.theme);
+    assert_eq!("de", inserted.language);
+    assert_eq!(Some("UTC".into()), inserted.timezone);
+    assert_eq!(2, UserSettings::count(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("composite_key"))]
+async fn upsert_without_updatable_columns_returns_existing_row(
+    pool: sqlx::PgPool,
+) -> sqlx::Result<()> {
+    let id = UserRoleId {
+        user_id: 1,
+        role_id: 2,
+    };
+    let existing = UserRole::find(&pool, &id).await?.unwrap();
+    let upserted = UserRoleDefault {
+        user_id: 1,
+        role_id: 2,
+        assigned_at: None,
+    }
+    .create_or_update(&pool)
+    .await?;
+    assert_eq!(existing, upserted);
+    Ok(())
+}
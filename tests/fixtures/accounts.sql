@@ -0,0 +1,3 @@
+INSERT INTO accounts (username, email, login_count)
+VALUES ('alice', 'alice@example.com', 3),
+       ('bob', NULL, 1);
@@ -0,0 +1,3 @@
+INSERT INTO tickets (title, status, previous_status, priority)
+VALUES ('Login fails', 'in_progress', 'open', 1),
+       ('Typo on homepage', 'open', NULL, 5);
@@ -0,0 +1,3 @@
+INSERT INTO invoices (net_cents, tax_cents, note)
+VALUES (1000, 200, 'First'),
+       (500, 100, 'Second');
@@ -0,0 +1,6 @@
+INSERT INTO documents (tenant_id, created_by, title)
+VALUES (1, 'alice', 'Roadmap'),
+       (2, 'bob', 'Budget');
+
+INSERT INTO audit_entries (id, action, recorded_by)
+VALUES (1, 'login', 'alice');
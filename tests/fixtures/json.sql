@@ -0,0 +1,3 @@
+INSERT INTO preferences (settings, metadata, tags)
+VALUES ('{"theme": "dark", "font_size": 14}', '{"source": "import"}', '["admin"]'),
+       ('{"theme": "light", "font_size": 12}', NULL, '[]');
@@ -0,0 +1,4 @@
+INSERT INTO legacy_orders ("customerName", "type", "order", "authorId")
+VALUES ('Alice', 'online', 1, 1),
+       ('Bob', 'store', 2, NULL),
+       ('Carol', 'online', 3, 1);
@@ -0,0 +1,11 @@
+INSERT INTO forums (name)
+VALUES ('General');
+
+INSERT INTO threads (forum_id, title, deleted_at)
+VALUES (1, 'Welcome', NULL),
+       (1, 'Rules', NULL),
+       (1, 'Spam', '2025-01-01 00:00:00+00');
+
+INSERT INTO replies (thread_id, body)
+VALUES (1, 'Hi!'),
+       (3, 'Buy now');
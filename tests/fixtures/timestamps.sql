@@ -0,0 +1,3 @@
+INSERT INTO notes (body, created_at, updated_at)
+VALUES ('First', '2020-01-01 00:00:00+00', '2020-01-02 00:00:00+00'),
+       ('Second', '2020-02-01 00:00:00+00', '2020-02-01 00:00:00+00');
@@ -0,0 +1,2 @@
+INSERT INTO user_settings (username, theme, language, notifications, items_per_page, timezone)
+VALUES ('alice', 'dark', 'fr', FALSE, 50, 'Europe/Paris');
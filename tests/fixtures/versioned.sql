@@ -0,0 +1,3 @@
+INSERT INTO wiki_pages (title, version)
+VALUES ('Home', 1),
+       ('About', 3);
@@ -0,0 +1,118 @@
+use georm::{Defaultable, Georm};
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "invoices")]
+struct Invoice {
+    #[georm(id, generated)]
+    id: i32,
+    net_cents: i32,
+    tax_cents: i32,
+    #[georm(generated)]
+    total_cents: i32,
+    #[georm(defaultable)]
+    note: String,
+}
+
+#[sqlx::test(fixtures("generated"))]
+async fn generated_columns_are_read(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let first = Invoice::find(&pool, &1).await?.unwrap();
+    assert_eq!(1200, first.total_cents);
+    assert_eq!(600, Invoice::find(&pool, &2).await?.unwrap().total_cents);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("generated"))]
+async fn create_leaves_generated_columns_out(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let invoice = Invoice {
+        id: 42,
+        net_cents: 300,
+        tax_cents: 50,
+        total_cents: 0,
+        note: "Consulting".into(),
+    };
+    let created = invoice.create(&pool).await?;
+    assert_eq!(3, created.id);
+    assert_eq!(350, created.total_cents);
+
+    let invoices = [
+        Invoice {
+            id: 0,
+            net_cents: 10,
+            tax_cents: 1,
+            total_cents: 0,
+            note: "Stamps".into(),
+        },
+        Invoice {
+            id: 0,
+            net_cents: 20,
+            tax_cents: 2,
+            total_cents: 0,
+            note: "Envelopes".into(),
+        },
+    ];
+    let created = Invoice::create_many(&invoices, &pool).await?;
+    let totals: Vec<(i32, i32)> = created.iter().map(|i| (i.id, i.total_cents)).collect();
+    assert_eq!(vec![(4, 11), (5, 22)], totals);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("generated"))]
+async fn update_leaves_generated_columns_out(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let mut first = Invoice::find(&pool, &1).await?.unwrap();
+    first.tax_cents = 0;
+    first.total_cents = -1;
+    let updated = first.update(&pool).await?;
+    assert_eq!(1000, updated.total_cents);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("generated"))]
+async fn upsert_writes_generated_primary_keys(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let first = Invoice::find(&pool, &1).await?.unwrap();
+    let updated = Invoice {
+        net_cents: 2000,
+        tax_cents: 400,
+        ..first
+    }
+    .create_or_update(&pool)
+    .await?;
+    assert_eq!(1, updated.id);
+    assert_eq!(2400, updated.total_cents);
+
+    let invoices = [
+        Invoice {
+            id: 2,
+            net_cents: 1,
+            tax_cents: 1,
+            total_cents: 0,
+            note: "Refund".into(),
+        },
+        Invoice {
+            id: 10,
+            net_cents: 5,
+            tax_cents: 5,
+            total_cents: 0,
+            note: "Late fee".into(),
+        },
+    ];
+    let upserted = Invoice::create_or_update_many(&invoices, &pool).await?;
+    let totals: Vec<(i32, i32)> = upserted.iter().map(|i| (i.id, i.total_cents)).collect();
+    assert_eq!(vec![(2, 2), (10, 10)], totals);
+    assert_eq!(3, Invoice::count(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn defaultable_create_leaves_generated_columns_out(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let created = InvoiceDefault {
+        net_cents: 100,
+        tax_cents: 20,
+        note: None,
+    }
+    .create(&pool)
+    .await?;
+    assert_eq!(1, created.id);
+    assert_eq!(120, created.total_cents);
+    assert_eq!("", created.note);
+    Ok(())
+}
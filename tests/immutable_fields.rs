@@ -0,0 +1,136 @@
+use georm::Georm;
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "documents")]
+struct Document {
+    #[georm(id)]
+    id: i32,
+    #[georm(immutable)]
+    tenant_id: i32,
+    #[georm(immutable)]
+    created_by: String,
+    title: String,
+}
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "audit_entries")]
+struct AuditEntry {
+    #[georm(id)]
+    id: i32,
+    #[georm(immutable)]
+    action: String,
+    #[georm(immutable)]
+    recorded_by: String,
+}
+
+#[sqlx::test(fixtures("immutable"))]
+async fn create_writes_immutable_fields(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let document = Document {
+        id: 3,
+        tenant_id: 7,
+        created_by: "carol".into(),
+        title: "Notes".into(),
+    };
+    let created = document.create(&pool).await?;
+    assert_eq!(document, created);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("immutable"))]
+async fn update_leaves_immutable_fields_untouched(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let stale = Document {
+        id: 1,
+        tenant_id: 99,
+        created_by: "mallory".into(),
+        title: "Roadmap v2".into(),
+    };
+    let updated = stale.update(&pool).await?;
+    let expected = Document {
+        id: 1,
+        tenant_id: 1,
+        created_by: "alice".into(),
+        title: "Roadmap v2".into(),
+    };
+    assert_eq!(expected, updated);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("immutable"))]
+async fn upsert_leaves_immutable_fields_untouched(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let updated = Document {
+        id: 2,
+        tenant_id: 99,
+        created_by: "mallory".into(),
+        title: "Budget 2026".into(),
+    }
+    .create_or_update(&pool)
+    .await?;
+    let expected = Document {
+        id: 2,
+        tenant_id: 2,
+        created_by: "bob".into(),
+        title: "Budget 2026".into(),
+    };
+    assert_eq!(expected, updated);
+
+    let document = Document {
+        id: 3,
+        tenant_id: 5,
+        created_by: "dave".into(),
+        title: "Plan".into(),
+    };
+    let inserted = document.create_or_update(&pool).await?;
+    assert_eq!(document, inserted);
+
+    let memo = Document {
+        id: 4,
+        tenant_id: 6,
+        created_by: "erin".into(),
+        title: "Memo".into(),
+    };
+    let documents = [
+        Document {
+            id: 1,
+            tenant_id: 99,
+            created_by: "mallory".into(),
+            title: "Roadmap v3".into(),
+        },
+        memo.clone(),
+    ];
+    let upserted = Document::create_or_update_many(&documents, &pool).await?;
+    let roadmap = Document {
+        id: 1,
+        tenant_id: 1,
+        created_by: "alice".into(),
+        title: "Roadmap v3".into(),
+    };
+    assert_eq!(vec![roadmap, memo], upserted);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("immutable"))]
+async fn entities_without_updatable_fields_are_left_untouched(
+    pool: sqlx::PgPool,
+) -> sqlx::Result<()> {
+    let login = AuditEntry {
+        id: 1,
+        action: "login".into(),
+        recorded_by: "alice".into(),
+    };
+    let tampered = AuditEntry {
+        id: 1,
+        action: "logout".into(),
+        recorded_by: "mallory".into(),
+    };
+    assert_eq!(login, tampered.update(&pool).await?);
+    assert_eq!(login, tampered.create_or_update(&pool).await?);
+
+    let logout = AuditEntry {
+        id: 2,
+        action: "logout".into(),
+        recorded_by: "alice".into(),
+    };
+    let upserted = AuditEntry::create_or_update_many(&[tampered, logout.clone()], &pool).await?;
+    assert_eq!(vec![login, logout], upserted);
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use georm::{Defaultable, Georm, PageRequest};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Settings {
+    theme: String,
+    font_size: u32,
+}
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "preferences")]
+struct Preference {
+    #[georm(id, defaultable)]
+    id: i32,
+    #[georm(json)]
+    settings: Settings,
+    #[georm(json)]
+    metadata: Option<HashMap<String, String>>,
+    #[georm(json, defaultable)]
+    tags: Vec<String>,
+}
+
+#[sqlx::test(fixtures("json"))]
+async fn json_fields_are_decoded(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let first = Preference::find(&pool, &1).await?.unwrap();
+    let expected = Settings {
+        theme: "dark".into(),
+        font_size: 14,
+    };
+    assert_eq!(expected, first.settings);
+    assert_eq!(
+        Some("import"),
+        first
+            .metadata
+            .as_ref()
+            .unwrap()
+            .get("source")
+            .map(String::as_str)
+    );
+    assert_eq!(vec!["admin".to_string()], first.tags);
+
+    let second = Preference::find(&pool, &2).await?.unwrap();
+    assert_eq!(None, second.metadata);
+    assert!(second.tags.is_empty());
+
+    assert_eq!(2, Preference::find_all(&pool).await?.len());
+    let page = Preference::find_page(&pool, PageRequest::first(5)).await?;
+    assert_eq!(vec![first, second], page.items);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("json"))]
+async fn json_fields_are_encoded(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let preference = Preference {
+        id: 3,
+        settings: Settings {
+            theme: "solarized".into(),
+            font_size: 16,
+        },
+        metadata: Some(HashMap::from([("source".into(), "test".into())])),
+        tags: vec!["new".into()],
+    };
+    let created = preference.create(&pool).await?;
+    assert_eq!(preference, created);
+
+    let mut updated = created.clone();
+    updated.settings.font_size = 20;
+    updated.metadata = None;
+    let updated = updated.update(&pool).await?;
+    assert_eq!(20, updated.settings.font_size);
+    assert_eq!(None, updated.metadata);
+
+    let preference = Preference {
+        id: 1,
+        settings: Settings {
+            theme: "nord".into(),
+            font_size: 16,
+        },
+        metadata: None,
+        tags: vec!["admin".into(), "beta".into()],
+    };
+    let upserted = preference.create_or_update(&pool).await?;
+    assert_eq!(preference, upserted);
+
+    let theme: String =
+        sqlx::query_scalar("SELECT settings->>'theme' FROM preferences WHERE id = 1")
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!("nord", theme);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("json"))]
+async fn json_fields_work_in_bulk_operations(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let preferences = [
+        Preference {
+            id: 3,
+            settings: Settings {
+                theme: "a".into(),
+                font_size: 16,
+            },
+            metadata: Some(HashMap::from([("source".into(), "test".into())])),
+            tags: vec!["new".into()],
+        },
+        Preference {
+            id: 4,
+            settings: Settings {
+                theme: "b".into(),
+                font_size: 12,
+            },
+            metadata: Some(HashMap::new()),
+            tags: Vec::new(),
+        },
+    ];
+    let created = Preference::create_many(&preferences, &pool).await?;
+    assert_eq!(preferences.to_vec(), created);
+
+    let preferences = [
+        Preference {
+            id: 4,
+            settings: Settings {
+                theme: "c".into(),
+                font_size: 12,
+            },
+            metadata: None,
+            tags: Vec::new(),
+        },
+        Preference {
+            id: 5,
+            settings: Settings {
+                theme: "d".into(),
+                font_size: 18,
+            },
+            metadata: Some(HashMap::from([("source".into(), "sync".into())])),
+            tags: vec!["new".into()],
+        },
+    ];
+    let upserted = Preference::create_or_update_many(&preferences, &pool).await?;
+    assert_eq!(preferences.to_vec(), upserted);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn json_fields_work_in_defaultable_create(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let created = PreferenceDefault {
+        id: None,
+        settings: Settings {
+            theme: "dark".into(),
+            font_size: 10,
+        },
+        metadata: None,
+        tags: None,
+    }
+    .create(&pool)
+    .await?;
+    assert_eq!(10, created.settings.font_size);
+    assert!(created.tags.is_empty());
+
+    let created = PreferenceDefault {
+        id: None,
+        settings: Settings {
+            theme: "light".into(),
+            font_size: 10,
+        },
+        metadata: Some(HashMap::new()),
+        tags: Some(vec!["beta".into()]),
+    }
+    .create(&pool)
+    .await?;
+    assert_eq!(Some(HashMap::new()), created.metadata);
+    assert_eq!(vec!["beta".to_string()], created.tags);
+    Ok(())
+}
@@ -18,3 +18,35 @@ async fn books_should_be_able_to_access_their_genres(pool: sqlx::PgPool) -> sqlx
     assert_eq!(2, genres.len());
     Ok(())
 }
+
+#[sqlx::test(fixtures("simple_struct", "o2o", "m2m"))]
+async fn m2m_getters_follow_entity_order(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let to_build_a_fire = Book::find(&pool, &4).await?.unwrap();
+    let genres = to_build_a_fire.get_genres(&pool).await?;
+    assert_eq!(
+        vec![3, 2],
+        genres.iter().map(|g| g.get_id()).collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct", "o2o", "m2m"))]
+async fn m2m_getters_follow_relationship_order(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let fantasy = Genre::find(&pool, &1).await?.unwrap();
+    let books = fantasy.get_books(&pool).await?;
+    assert_eq!(
+        vec![2, 3, 1],
+        books.iter().map(|b| b.get_id()).collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct", "o2o", "m2m"))]
+async fn find_all_follows_struct_order(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let genres = Genre::find_all(&pool).await?;
+    assert_eq!(
+        vec![3, 1, 2],
+        genres.iter().map(|g| g.get_id()).collect::<Vec<_>>()
+    );
+    Ok(())
+}
@@ -14,7 +14,13 @@ pub struct Biography {
 }
 
 #[derive(Debug, Georm, PartialEq, Eq, Default)]
-#[georm(table = "authors")]
+#[georm(
+    table = "authors",
+    patch,
+    one_to_many = [{
+        name = "legacy_orders", remote_id = "\"authorId\"", table = "legacy_orders", entity = LegacyOrder
+    }]
+)]
 pub struct Author {
     #[georm(id)]
     pub id: i32,
@@ -39,7 +45,7 @@ impl Ord for Author {
 #[georm(
     table = "books",
     one_to_many = [
-        { name = "reviews", remote_id = "book_id", table = "reviews", entity = Review }
+        { name = "reviews", remote_id = "book_id", table = "reviews", entity = Review, order_by = "id DESC" }
     ],
     many_to_many = [{
         name = "genres",
@@ -81,12 +87,14 @@ pub struct Review {
 #[derive(Debug, Georm, PartialEq, Eq)]
 #[georm(
     table = "genres",
+    order_by = "name",
     many_to_many = [{
         name = "books",
         table = "books",
         entity = Book,
         remote_id = "ident",
-        link = { table = "book_genres", from = "genre_id", to = "book_id" }
+        link = { table = "book_genres", from = "genre_id", to = "book_id" },
+        order_by = "title DESC"
     }]
 )]
 pub struct Genre {
@@ -96,7 +104,7 @@ pub struct Genre {
 }
 
 #[derive(Debug, Georm, PartialEq, Eq, Default)]
-#[georm(table = "UserRoles")]
+#[georm(table = "UserRoles", patch)]
 pub struct UserRole {
     #[georm(id)]
     pub user_id: i32,
@@ -105,3 +113,17 @@ pub struct UserRole {
     #[georm(defaultable)]
     pub assigned_at: chrono::DateTime<chrono::Utc>,
 }
+
+#[derive(Debug, Georm, PartialEq, Eq, Clone)]
+#[georm(table = "legacy_orders", rename_all = "camelCase", patch)]
+pub struct LegacyOrder {
+    #[georm(id, defaultable)]
+    pub order_id: i32,
+    pub customer_name: String,
+    #[georm(column = "type")]
+    pub kind: String,
+    #[georm(defaultable)]
+    pub r#order: i32,
+    #[georm(relation = {entity = Author, table = "authors", name = "author", nullable = true})]
+    pub author_id: Option<i32>,
+}
@@ -31,3 +31,11 @@ async fn books_can_have_no_reviews(pool: sqlx::PgPool) -> sqlx::Result<()> {
     assert_eq!(0, reviews.len());
     Ok(())
 }
+
+#[sqlx::test(fixtures("simple_struct", "o2o"))]
+async fn books_reviews_follow_relationship_order(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let book = Book::find(&pool, &2).await?.unwrap();
+    let reviews = book.get_reviews(&pool).await?;
+    assert_eq!(vec![4, 3], reviews.iter().map(|r| r.id).collect::<Vec<_>>());
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use chrono::{TimeZone, Utc};
+use georm::Georm;
+
+mod models;
+use models::*;
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn patch_only_writes_set_fields(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let patch = AuthorPatch {
+        name: Some("Eric Arthur Blair".into()),
+        ..Default::default()
+    };
+    let patched = patch.apply(&pool, &1).await?;
+    let expected = Author {
+        id: 1,
+        name: "Eric Arthur Blair".into(),
+        biography_id: Some(2),
+    };
+    assert_eq!(expected, patched);
+    assert_eq!(Some(expected), Author::find(&pool, &1).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn patch_can_set_columns_to_null(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let patch = AuthorPatch {
+        biography_id: Some(None),
+        ..Default::default()
+    };
+    let patched = patch.apply(&pool, &3).await?;
+    assert_eq!(None, patched.biography_id);
+    assert_eq!("Jack London", patched.name);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn empty_patch_returns_entity_unchanged(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let patched = AuthorPatch::default().apply(&pool, &2).await?;
+    assert_eq!(Author::find(&pool, &2).await?, Some(patched));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn patch_on_missing_entity_fails(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let patch = AuthorPatch {
+        name: Some("Nobody".into()),
+        ..Default::default()
+    };
+    let error = patch.apply(&pool, &42).await.unwrap_err();
+    assert!(matches!(error, sqlx::Error::RowNotFound));
+    let error = AuthorPatch::default().apply(&pool, &42).await.unwrap_err();
+    assert!(matches!(error, sqlx::Error::RowNotFound));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("composite_key"))]
+async fn patch_works_with_composite_keys(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let assigned_at = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+    let patch = UserRolePatch {
+        assigned_at: Some(assigned_at),
+    };
+    let id = UserRoleId {
+        user_id: 1,
+        role_id: 2,
+    };
+    let patched = patch.apply(&pool, &id).await?;
+    assert_eq!(assigned_at, patched.assigned_at);
+    let untouched = UserRole::find(
+        &pool,
+        &UserRoleId {
+            user_id: 1,
+            role_id: 1,
+        },
+    )
+    .await?;
+    assert_ne!(assigned_at, untouched.unwrap().assigned_at);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct", "renamed_columns"))]
+async fn patch_writes_renamed_columns(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let patch = LegacyOrderPatch {
+        kind: Some("express".into()),
+        r#order: Some(7),
+        ..Default::default()
+    };
+    let patched = patch.apply(&pool, &1).await?;
+    assert_eq!("express", patched.kind);
+    assert_eq!(7, patched.r#order);
+    assert_eq!(Some(patched), LegacyOrder::find(&pool, &1).await?);
+    Ok(())
+}
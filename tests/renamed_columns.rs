@@ -0,0 +1,144 @@
+use georm::{Defaultable, Georm, PageRequest};
+
+mod models;
+use models::{Author, LegacyOrder, LegacyOrderDefault};
+
+#[sqlx::test(fixtures("simple_struct", "renamed_columns"))]
+async fn renamed_columns_are_read(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let all = LegacyOrder::find_all(&pool).await?;
+    assert_eq!(3, all.len());
+    let bob = LegacyOrder::find(&pool, &2).await?.unwrap();
+    assert_eq!("Bob", bob.customer_name);
+    assert_eq!("store", bob.kind);
+    assert_eq!(2, bob.r#order);
+    assert_eq!(2, LegacyOrder::find_many(&pool, &[1, 3]).await?.len());
+    assert!(LegacyOrder::exists(&pool, &3).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct", "renamed_columns"))]
+async fn renamed_columns_are_written(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let order = LegacyOrder {
+        order_id: 10,
+        customer_name: "Dave".into(),
+        kind: "phone".into(),
+        r#order: 4,
+        author_id: None,
+    };
+    let created = order.create(&pool).await?;
+    assert_eq!(order, created);
+
+    let mut bob = LegacyOrder::find(&pool, &2).await?.unwrap();
+    bob.kind = "online".into();
+    let updated = bob.update(&pool).await?;
+    assert_eq!("online", updated.kind);
+
+    let order = LegacyOrder {
+        order_id: 2,
+        customer_name: "Robert".into(),
+        kind: "store".into(),
+        r#order: 5,
+        author_id: None,
+    };
+    let upserted = order.create_or_update(&pool).await?;
+    assert_eq!(order, upserted);
+
+    assert_eq!(1, LegacyOrder::delete_by_id(&pool, &1).await?);
+    assert_eq!(1, LegacyOrder::delete_many_by_ids(&pool, &[3]).await?);
+    assert_eq!(2, LegacyOrder::count(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct", "renamed_columns"))]
+async fn renamed_columns_work_in_bulk_operations(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let orders = [
+        LegacyOrder {
+            order_id: 10,
+            customer_name: "Dave".into(),
+            kind: "phone".into(),
+            r#order: 4,
+            author_id: None,
+        },
+        LegacyOrder {
+            order_id: 11,
+            customer_name: "Eve".into(),
+            kind: "store".into(),
+            r#order: 5,
+            author_id: Some(2),
+        },
+    ];
+    let created = LegacyOrder::create_many(&orders, &pool).await?;
+    assert_eq!(
+        vec![10, 11],
+        created.iter().map(|o| o.order_id).collect::<Vec<_>>()
+    );
+
+    let orders = [
+        LegacyOrder {
+            order_id: 11,
+            customer_name: "Eve".into(),
+            kind: "online".into(),
+            r#order: 6,
+            author_id: Some(2),
+        },
+        LegacyOrder {
+            order_id: 1,
+            customer_name: "Alice".into(),
+            kind: "store".into(),
+            r#order: 7,
+            author_id: Some(1),
+        },
+    ];
+    let upserted = LegacyOrder::create_or_update_many(&orders, &pool).await?;
+    assert_eq!(orders.to_vec(), upserted);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct", "renamed_columns"))]
+async fn renamed_columns_work_with_pagination(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let first = LegacyOrder::find_page(&pool, PageRequest::first(2)).await?;
+    assert_eq!(
+        vec![1, 2],
+        first.items.iter().map(|o| o.order_id).collect::<Vec<_>>()
+    );
+    let second =
+        LegacyOrder::find_page(&pool, PageRequest::after(first.next_cursor.unwrap(), 2)).await?;
+    assert_eq!(
+        vec![3],
+        second.items.iter().map(|o| o.order_id).collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct", "renamed_columns"))]
+async fn renamed_columns_work_in_defaultable_create(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let created = LegacyOrderDefault {
+        order_id: None,
+        customer_name: "Dave".into(),
+        kind: "phone".into(),
+        r#order: None,
+        author_id: Some(3),
+    }
+    .create(&pool)
+    .await?;
+    assert_eq!(4, created.order_id);
+    assert_eq!(0, created.r#order);
+    assert_eq!("phone", created.kind);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct", "renamed_columns"))]
+async fn renamed_columns_work_in_relationships(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let alice = LegacyOrder::find(&pool, &1).await?.unwrap();
+    let author = alice.get_author(&pool).await?.unwrap();
+    assert_eq!(1, author.id);
+    let mut orders = author.get_legacy_orders(&pool).await?;
+    orders.sort_by_key(|o| o.order_id);
+    assert_eq!(
+        vec![1, 3],
+        orders.iter().map(|o| o.order_id).collect::<Vec<_>>()
+    );
+    let tolkien = Author::find(&pool, &2).await?.unwrap();
+    assert!(tolkien.get_legacy_orders(&pool).await?.is_empty());
+    Ok(())
+}
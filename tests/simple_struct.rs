@@ -1,7 +1,8 @@
-use georm::Georm;
+use futures_util::TryStreamExt;
+use georm::{Georm, PageRequest};
 use rand::seq::SliceRandom;
 
-use models::Author;
+use models::{Author, Book, Genre};
 mod models;
 
 #[sqlx::test(fixtures("simple_struct"))]
@@ -165,3 +166,354 @@ async fn delete_should_delete_current_entity_from_db(pool: sqlx::PgPool) -> sqlx
     assert!(all_authors.iter().all(|a| a.get_id() != author.get_id()));
     Ok(())
 }
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn operations_run_inside_committed_transaction(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let author = Author {
+        id: 4,
+        name: "Miura Kentaro".into(),
+        ..Default::default()
+    };
+    let mut tx = pool.begin().await?;
+    author.create(&mut *tx).await?;
+    Author::delete_by_id(&mut *tx, &2).await?;
+    let all_authors = Author::find_all(&mut *tx).await?;
+    assert_eq!(3, all_authors.len());
+    tx.commit().await?;
+    assert_eq!(Some(author), Author::find(&pool, &4).await?);
+    assert!(Author::find(&pool, &2).await?.is_none());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn operations_are_discarded_on_rollback(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let mut tx = pool.begin().await?;
+    let mut tolkien = Author::find(&mut *tx, &1).await?.unwrap();
+    tolkien.name = "Jolkien Rolkien Rolkien Tolkien".into();
+    tolkien.update(&mut *tx).await?;
+    Author::delete_by_id(&mut *tx, &2).await?;
+    tx.rollback().await?;
+    let tolkien = Author::find(&pool, &1).await?.unwrap();
+    assert_eq!("J.R.R. Tolkien", tolkien.name);
+    assert_eq!(3, Author::find_all(&pool).await?.len());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn operations_accept_a_plain_connection(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let mut conn = pool.acquire().await?;
+    let author = Author {
+        id: 4,
+        name: "Miura Kentaro".into(),
+        ..Default::default()
+    };
+    author.create_or_update(&mut *conn).await?;
+    assert_eq!(4, Author::find_all(&mut *conn).await?.len());
+    assert_eq!(1, author.delete(&mut *conn).await?);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_many_works(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let authors = vec![
+        Author {
+            id: 1,
+            name: "J.R.R. Tolkien".into(),
+            ..Default::default()
+        },
+        Author {
+            id: 2,
+            name: "George Orwell".into(),
+            ..Default::default()
+        },
+        Author {
+            id: 3,
+            name: "Jack London".into(),
+            ..Default::default()
+        },
+    ];
+    let created = Author::create_many(&authors, &pool).await?;
+    assert_eq!(authors, created);
+    let mut all_authors = Author::find_all(&pool).await?;
+    all_authors.sort();
+    assert_eq!(authors, all_authors);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_many_returns_entities_in_input_order(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let authors: Vec<Author> = [3, 1, 2]
+        .into_iter()
+        .map(|id| Author {
+            id,
+            name: format!("Author {id}"),
+            ..Default::default()
+        })
+        .collect();
+    let created = Author::create_many(&authors, &pool).await?;
+    assert_eq!(authors, created);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_many_with_empty_slice_does_nothing(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let created = Author::create_many(&[], &pool).await?;
+    assert!(created.is_empty());
+    assert!(Author::find_all(&pool).await?.is_empty());
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_many_splits_large_slices_into_batches(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let authors: Vec<Author> = (1..=50_000)
+        .map(|id| Author {
+            id,
+            name: format!("Author {id}"),
+            ..Default::default()
+        })
+        .collect();
+    let created = Author::create_many(&authors, &pool).await?;
+    assert_eq!(authors, created);
+    assert_eq!(50_000, Author::find_all(&pool).await?.len());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn create_many_is_atomic(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let authors = vec![
+        Author {
+            id: 4,
+            name: "Miura Kentaro".into(),
+            ..Default::default()
+        },
+        Author {
+            id: 1,
+            name: "Duplicate".into(),
+            ..Default::default()
+        },
+    ];
+    let result = Author::create_many(&authors, &pool).await;
+    assert!(result.is_err());
+    assert!(Author::find(&pool, &4).await?.is_none());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn create_or_update_many_works(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let authors = vec![
+        Author {
+            id: 5,
+            name: "Inoue Takehiko".into(),
+            ..Default::default()
+        },
+        Author {
+            id: 2,
+            name: "Miura Kentaro".into(),
+            ..Default::default()
+        },
+        Author {
+            id: 4,
+            name: "Urasawa Naoki".into(),
+            ..Default::default()
+        },
+    ];
+    let upserted = Author::create_or_update_many(&authors, &pool).await?;
+    assert_eq!(authors, upserted);
+    let mut all_authors = Author::find_all(&pool).await?;
+    all_authors.sort();
+    assert_eq!(5, all_authors.len());
+    assert_eq!(authors[1], all_authors[1]);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn create_or_update_many_fails_on_duplicate_ids(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let authors = vec![
+        Author {
+            id: 2,
+            name: "Miura Kentaro".into(),
+            ..Default::default()
+        },
+        Author {
+            id: 2,
+            name: "Inoue Takehiko".into(),
+            ..Default::default()
+        },
+    ];
+    let result = Author::create_or_update_many(&authors, &pool).await;
+    assert!(result.is_err());
+    let orwell = Author::find(&pool, &2).await?.unwrap();
+    assert_eq!("George Orwell", orwell.name);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn find_many_works(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let mut authors = Author::find_many(&pool, &[1, 3, 42]).await?;
+    authors.sort();
+    assert_eq!(2, authors.len());
+    assert_eq!(1, authors[0].id);
+    assert_eq!(3, authors[1].id);
+    assert!(Author::find_many(&pool, &[]).await?.is_empty());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn delete_many_by_ids_works(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let result = Author::delete_many_by_ids(&pool, &[2, 3, 42]).await?;
+    assert_eq!(2, result);
+    let all_authors = Author::find_all(&pool).await?;
+    assert_eq!(1, all_authors.len());
+    assert_eq!(1, all_authors[0].id);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn find_all_stream_works(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let mut streamed: Vec<Author> = Author::find_all_stream(&pool).try_collect().await?;
+    streamed.sort();
+    let mut all_authors = Author::find_all(&pool).await?;
+    all_authors.sort();
+    assert_eq!(all_authors, streamed);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn find_all_stream_is_empty_on_empty_table(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let mut stream = Author::find_all_stream(&pool);
+    assert!(stream.try_next().await?.is_none());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn find_all_stream_accepts_a_transaction(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let mut tx = pool.begin().await?;
+    Author::delete_by_id(&mut *tx, &1).await?;
+    let streamed: Vec<Author> = Author::find_all_stream(&mut *tx).try_collect().await?;
+    assert_eq!(2, streamed.len());
+    tx.rollback().await?;
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn find_page_walks_table_in_id_order(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let first = Author::find_page(&pool, PageRequest::first(2)).await?;
+    assert_eq!(
+        vec![1, 2],
+        first.items.iter().map(|a| a.id).collect::<Vec<_>>()
+    );
+    let cursor = first.next_cursor.expect("a second page should exist");
+    let cursor = cursor
+        .to_string()
+        .parse()
+        .expect("cursor should round-trip");
+    let second = Author::find_page(&pool, PageRequest::after(cursor, 2)).await?;
+    assert_eq!(
+        vec![3],
+        second.items.iter().map(|a| a.id).collect::<Vec<_>>()
+    );
+    assert!(second.next_cursor.is_none());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn find_page_has_no_next_cursor_on_exact_last_page(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let page = Author::find_page(&pool, PageRequest::first(3)).await?;
+    assert_eq!(3, page.items.len());
+    assert!(page.next_cursor.is_none());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn find_page_cursor_survives_deleted_entity(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let first = Author::find_page(&pool, PageRequest::first(1)).await?;
+    let cursor = first.next_cursor.unwrap();
+    Author::delete_by_id(&pool, &1).await?;
+    let second = Author::find_page(&pool, PageRequest::after(cursor, 5)).await?;
+    assert_eq!(
+        vec![2, 3],
+        second.items.iter().map(|a| a.id).collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[sqlx::test]
+async fn find_page_returns_empty_page_on_empty_table(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let page = Author::find_page(&pool, PageRequest::first(10)).await?;
+    assert!(page.items.is_empty());
+    assert!(page.next_cursor.is_none());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn find_page_treats_zero_limit_as_one(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let page = Author::find_page(&pool, PageRequest::first(0)).await?;
+    assert_eq!(1, page.items.len());
+    assert!(page.next_cursor.is_some());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn find_page_rejects_cursor_of_another_entity(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let page = Author::find_page(&pool, PageRequest::first(1)).await?;
+    let cursor = page.next_cursor.unwrap();
+    let result = Book::find_page(&pool, PageRequest::after(cursor, 1)).await;
+    assert!(matches!(result, Err(sqlx::Error::Decode(_))));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn find_page_rejects_cursor_of_entity_with_same_key_names(
+    pool: sqlx::PgPool,
+) -> sqlx::Result<()> {
+    // Both authors and genres have a primary key named `id`
+    let page = Author::find_page(&pool, PageRequest::first(1)).await?;
+    let cursor = page.next_cursor.unwrap();
+    let result = Genre::find_page(&pool, PageRequest::after(cursor, 1)).await;
+    assert!(matches!(result, Err(sqlx::Error::Decode(_))));
+    Ok(())
+}
+
+#[test]
+fn cursor_rejects_invalid_tokens() {
+    assert!("zz".parse::<georm::Cursor>().is_err());
+    assert!("abc".parse::<georm::Cursor>().is_err());
+    assert!("ff".parse::<georm::Cursor>().is_err());
+    // Valid hexadecimal, but without a table
+    assert!("3432".parse::<georm::Cursor>().is_err());
+    // A table, but a key that is not a JSON object
+    assert!("617574686f7273003432".parse::<georm::Cursor>().is_err());
+}
+
+#[cfg(feature = "serde")]
+#[sqlx::test(fixtures("simple_struct"))]
+async fn cursor_round_trips_through_serde(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let page = Author::find_page(&pool, PageRequest::first(1)).await?;
+    let cursor = page.next_cursor.unwrap();
+    let json = serde_json::to_string(&cursor).unwrap();
+    assert_eq!(format!("\"{cursor}\""), json);
+    let parsed: georm::Cursor = serde_json::from_str(&json).unwrap();
+    assert_eq!(cursor, parsed);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn count_works(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    assert_eq!(3, Author::count(&pool).await?);
+    Author::delete_by_id(&pool, &1).await?;
+    assert_eq!(2, Author::count(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn count_returns_zero_on_empty_table(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    assert_eq!(0, Author::count(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn exists_works(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    assert!(Author::exists(&pool, &1).await?);
+    assert!(!Author::exists(&pool, &42).await?);
+    Ok(())
+}
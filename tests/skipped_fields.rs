@@ -0,0 +1,99 @@
+use georm::{Defaultable, Georm, PageRequest};
+
+fn unknown() -> String {
+    "unknown".into()
+}
+
+#[derive(Georm, Debug, PartialEq, Eq)]
+#[georm(table = "authors")]
+struct CachedAuthor {
+    #[georm(id, defaultable)]
+    id: i32,
+    name: String,
+    biography_id: Option<i32>,
+    #[georm(skip)]
+    book_titles: Vec<String>,
+    #[georm(skip, default = unknown)]
+    status: String,
+}
+
+impl CachedAuthor {
+    fn new(id: i32, name: &str) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            biography_id: None,
+            book_titles: vec!["cached".into()],
+            status: "cached".into(),
+        }
+    }
+}
+
+fn assert_defaults(author: &CachedAuthor) {
+    assert!(author.book_titles.is_empty());
+    assert_eq!("unknown", author.status);
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn skipped_fields_get_their_default_when_read(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let authors = CachedAuthor::find_all(&pool).await?;
+    assert_eq!(3, authors.len());
+    authors.iter().for_each(assert_defaults);
+
+    let orwell = CachedAuthor::find(&pool, &2).await?.unwrap();
+    assert_eq!("George Orwell", orwell.name);
+    assert_defaults(&orwell);
+
+    CachedAuthor::find_many(&pool, &[1, 3])
+        .await?
+        .iter()
+        .for_each(assert_defaults);
+    CachedAuthor::find_page(&pool, PageRequest::first(2))
+        .await?
+        .items
+        .iter()
+        .for_each(assert_defaults);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn skipped_fields_are_not_written(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let created = CachedAuthor::new(4, "Ursula K. Le Guin")
+        .create(&pool)
+        .await?;
+    assert_eq!("Ursula K. Le Guin", created.name);
+    assert_defaults(&created);
+
+    let mut orwell = CachedAuthor::find(&pool, &2).await?.unwrap();
+    orwell.book_titles = vec!["1984".into()];
+    orwell.name = "Eric Blair".into();
+    let updated = orwell.update(&pool).await?;
+    assert_eq!("Eric Blair", updated.name);
+    assert_defaults(&updated);
+
+    let upserted = CachedAuthor::new(2, "George Orwell")
+        .create_or_update(&pool)
+        .await?;
+    assert_defaults(&upserted);
+
+    let created = CachedAuthor::create_many(&[CachedAuthor::new(5, "Mary Shelley")], &pool).await?;
+    assert_defaults(&created[0]);
+    let upserted =
+        CachedAuthor::create_or_update_many(&[CachedAuthor::new(5, "Mary Shelley")], &pool).await?;
+    assert_defaults(&upserted[0]);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn skipped_fields_are_not_part_of_defaultable_struct(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let created = CachedAuthorDefault {
+        id: None,
+        name: "Octavia E. Butler".into(),
+        biography_id: None,
+    }
+    .create(&pool)
+    .await?;
+    assert_eq!("Octavia E. Butler", created.name);
+    assert_defaults(&created);
+    Ok(())
+}
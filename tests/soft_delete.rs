@@ -0,0 +1,146 @@
+use chrono::{DateTime, Utc};
+use georm::{Georm, PageRequest};
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(
+    table = "forums",
+    one_to_many = [{ name = "threads", remote_id = "forum_id", table = "threads", entity = Thread, order_by = "id" }]
+)]
+struct Forum {
+    #[georm(id)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "threads")]
+struct Thread {
+    #[georm(id)]
+    id: i32,
+    #[georm(relation = {entity = Forum, table = "forums", name = "forum"})]
+    forum_id: i32,
+    title: String,
+    #[georm(soft_delete)]
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "replies")]
+struct Reply {
+    #[georm(id)]
+    id: i32,
+    #[georm(relation = {entity = Thread, table = "threads", name = "thread"})]
+    thread_id: i32,
+    body: String,
+}
+
+fn titles(threads: &[Thread]) -> Vec<&str> {
+    threads.iter().map(|thread| thread.title.as_str()).collect()
+}
+
+async fn is_in_table(pool: &sqlx::PgPool, id: i32) -> sqlx::Result<bool> {
+    sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM threads WHERE id = $1)")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+}
+
+#[sqlx::test(fixtures("soft_delete"))]
+async fn reads_leave_deleted_rows_out(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    assert_eq!(None, Thread::find(&pool, &3).await?);
+    assert_eq!(
+        vec!["Welcome", "Rules"],
+        titles(&Thread::find_all(&pool).await?)
+    );
+    assert_eq!(1, Thread::find_many(&pool, &[2, 3]).await?.len());
+    assert_eq!(2, Thread::count(&pool).await?);
+    assert!(!Thread::exists(&pool, &3).await?);
+    assert!(Thread::exists(&pool, &1).await?);
+
+    let page = Thread::find_page(&pool, PageRequest::first(1)).await?;
+    assert_eq!(vec!["Welcome"], titles(&page.items));
+    let cursor = page.next_cursor.unwrap();
+    let page = Thread::find_page(&pool, PageRequest::after(cursor, 5)).await?;
+    assert_eq!(vec!["Rules"], titles(&page.items));
+    assert!(page.next_cursor.is_none());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("soft_delete"))]
+async fn relationship_getters_leave_deleted_rows_out(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let forum = Forum::find(&pool, &1).await?.unwrap();
+    assert_eq!(
+        vec!["Welcome", "Rules"],
+        titles(&forum.get_threads(&pool).await?)
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("soft_delete"))]
+async fn required_relations_return_deleted_rows(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let reply = Reply::find(&pool, &2).await?.unwrap();
+    let thread = reply.get_thread(&pool).await?;
+    assert_eq!("Spam", thread.title);
+    assert!(thread.deleted_at.is_some());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("soft_delete"))]
+async fn delete_marks_rows_as_deleted(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let thread = Thread::find(&pool, &1).await?.unwrap();
+    assert_eq!(1, thread.delete(&pool).await?);
+    assert_eq!(None, Thread::find(&pool, &1).await?);
+    assert!(is_in_table(&pool, 1).await?);
+    let deleted = Thread::find_with_deleted(&pool, &1).await?.unwrap();
+    assert!(deleted.deleted_at.is_some());
+
+    assert_eq!(0, thread.delete(&pool).await?);
+    assert_eq!(0, Thread::delete_by_id(&pool, &3).await?);
+    assert_eq!(1, Thread::delete_many_by_ids(&pool, &[2, 3]).await?);
+    assert_eq!(0, Thread::count(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("soft_delete"))]
+async fn find_with_deleted_reads_every_row(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let deleted = Thread::find_with_deleted(&pool, &3).await?.unwrap();
+    assert_eq!("Spam", deleted.title);
+    assert!(deleted.deleted_at.is_some());
+    let kept = Thread::find_with_deleted(&pool, &1).await?.unwrap();
+    assert_eq!(None, kept.deleted_at);
+    assert_eq!(None, Thread::find_with_deleted(&pool, &4).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("soft_delete"))]
+async fn restore_brings_rows_back(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let deleted = Thread::find_with_deleted(&pool, &3).await?.unwrap();
+    let restored = deleted.restore(&pool).await?;
+    assert_eq!(None, restored.deleted_at);
+    assert_eq!(Some(restored), Thread::find(&pool, &3).await?);
+    assert_eq!(3, Thread::count(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("soft_delete"))]
+async fn hard_delete_removes_rows(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let deleted = Thread::find_with_deleted(&pool, &3).await?.unwrap();
+    assert_eq!(1, deleted.hard_delete(&pool).await?);
+    assert!(!is_in_table(&pool, 3).await?);
+
+    let kept = Thread::find(&pool, &1).await?.unwrap();
+    assert_eq!(1, kept.hard_delete(&pool).await?);
+    assert!(!is_in_table(&pool, 1).await?);
+    assert_eq!(0, kept.hard_delete(&pool).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("soft_delete"))]
+async fn updates_keep_the_deletion_mark(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let mut thread = Thread::find(&pool, &1).await?.unwrap();
+    thread.delete(&pool).await?;
+    thread.title = "Hello".into();
+    let updated = thread.update(&pool).await?;
+    assert!(updated.deleted_at.is_some());
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use chrono::{DateTime, TimeZone, Utc};
+use georm::{Defaultable, Georm};
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "notes", patch)]
+struct Note {
+    #[georm(id, defaultable)]
+    id: i32,
+    body: String,
+    #[georm(created_at)]
+    created_at: DateTime<Utc>,
+    #[georm(updated_at)]
+    updated_at: DateTime<Utc>,
+}
+
+fn fixture_date(month: u32, day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2020, month, day, 0, 0, 0).unwrap()
+}
+
+async fn database_now(pool: &sqlx::PgPool) -> sqlx::Result<DateTime<Utc>> {
+    sqlx::query_scalar("SELECT now()").fetch_one(pool).await
+}
+
+#[sqlx::test(fixtures("timestamps"))]
+async fn create_sets_both_timestamps(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let before = database_now(&pool).await?;
+    let created = Note {
+        id: 3,
+        body: "Third".into(),
+        created_at: DateTime::UNIX_EPOCH,
+        updated_at: DateTime::UNIX_EPOCH,
+    }
+    .create(&pool)
+    .await?;
+    assert!(created.created_at >= before);
+    assert_eq!(created.created_at, created.updated_at);
+
+    let notes = [
+        Note {
+            id: 4,
+            body: "Fourth".into(),
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+        },
+        Note {
+            id: 5,
+            body: "Fifth".into(),
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+        },
+    ];
+    let created = Note::create_many(&notes, &pool).await?;
+    assert!(created.iter().all(|note| note.created_at >= before));
+    assert!(created.iter().all(|note| note.updated_at >= before));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("timestamps"))]
+async fn update_only_sets_updated_at(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let before = database_now(&pool).await?;
+    let mut first = Note::find(&pool, &1).await?.unwrap();
+    first.body = "First, edited".into();
+    first.created_at = DateTime::UNIX_EPOCH;
+    let updated = first.update(&pool).await?;
+    assert_eq!(fixture_date(1, 1), updated.created_at);
+    assert!(updated.updated_at >= before);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("timestamps"))]
+async fn upsert_keeps_created_at(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let before = database_now(&pool).await?;
+    let updated = Note {
+        id: 1,
+        body: "First, upserted".into(),
+        created_at: DateTime::UNIX_EPOCH,
+        updated_at: DateTime::UNIX_EPOCH,
+    }
+    .create_or_update(&pool)
+    .await?;
+    assert_eq!(fixture_date(1, 1), updated.created_at);
+    assert!(updated.updated_at >= before);
+
+    let notes = [
+        Note {
+            id: 2,
+            body: "Second, upserted".into(),
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+        },
+        Note {
+            id: 3,
+            body: "Third".into(),
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+        },
+    ];
+    let upserted = Note::create_or_update_many(&notes, &pool).await?;
+    assert_eq!(fixture_date(2, 1), upserted[0].created_at);
+    assert!(upserted[0].updated_at >= before);
+    assert!(upserted[1].created_at >= before);
+    Ok(())
+}
+
+#[sqlx::test]
+async fn defaultable_create_sets_both_timestamps(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let before = database_now(&pool).await?;
+    let created = NoteDefault {
+        id: None,
+        body: "Draft".into(),
+    }
+    .create(&pool)
+    .await?;
+    assert!(created.created_at >= before);
+    assert_eq!(created.created_at, created.updated_at);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("timestamps"))]
+async fn patch_only_sets_updated_at(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let before = database_now(&pool).await?;
+    let patch = NotePatch {
+        body: Some("First, patched".into()),
+    };
+    let patched = patch.apply(&pool, &1).await?;
+    assert_eq!(fixture_date(1, 1), patched.created_at);
+    assert!(patched.updated_at >= before);
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use georm::Georm;
+
+mod models;
+use models::Author;
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn transaction_commits_on_ok(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let created = georm::transaction(&pool, async |conn| {
+        let author = Author {
+            id: 4,
+            name: "Miura Kentaro".into(),
+            ..Default::default()
+        }
+        .create(&mut *conn)
+        .await?;
+        Author::delete_by_id(&mut *conn, &2).await?;
+        Ok::<_, sqlx::Error>(author)
+    })
+    .await?;
+    assert_eq!(Some(created), Author::find(&pool, &4).await?);
+    assert!(Author::find(&pool, &2).await?.is_none());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn transaction_rolls_back_on_err(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let result = georm::transaction(&pool, async |conn| {
+        Author {
+            id: 4,
+            name: "Miura Kentaro".into(),
+            ..Default::default()
+        }
+        .create(&mut *conn)
+        .await?;
+        // Duplicate primary key, fails the whole transaction
+        Author {
+            id: 1,
+            name: "Someone Else".into(),
+            ..Default::default()
+        }
+        .create(&mut *conn)
+        .await
+    })
+    .await;
+    assert!(result.is_err());
+    assert!(Author::find(&pool, &4).await?.is_none());
+    assert_eq!(3, Author::find_all(&pool).await?.len());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn transaction_rolls_back_on_custom_error(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    #[derive(Debug)]
+    enum Error {
+        Database,
+        Aborted,
+    }
+    impl From<sqlx::Error> for Error {
+        fn from(_: sqlx::Error) -> Self {
+            Self::Database
+        }
+    }
+
+    let result: Result<(), Error> = georm::transaction(&pool, async |conn| {
+        Author::delete_by_id(&mut *conn, &1).await?;
+        Err(Error::Aborted)
+    })
+    .await;
+    assert!(matches!(result, Err(Error::Aborted)));
+    assert!(Author::find(&pool, &1).await?.is_some());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn transaction_keeps_closure_error_when_rollback_fails(
+    pool: sqlx::PgPool,
+) -> sqlx::Result<()> {
+    #[derive(Debug)]
+    enum Error {
+        Database,
+        Aborted,
+    }
+    impl From<sqlx::Error> for Error {
+        fn from(_: sqlx::Error) -> Self {
+            Self::Database
+        }
+    }
+
+    let mut tx = pool.begin().await?;
+    let result: Result<(), Error> = georm::transaction(&mut tx, async |conn| {
+        // Rolling back to a released savepoint fails
+        sqlx::query("RELEASE SAVEPOINT _sqlx_savepoint_1")
+            .execute(&mut *conn)
+            .await?;
+        Err(Error::Aborted)
+    })
+    .await;
+    assert!(matches!(result, Err(Error::Aborted)));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn nested_transaction_failure_only_rolls_back_savepoint(
+    pool: sqlx::PgPool,
+) -> sqlx::Result<()> {
+    georm::transaction(&pool, async |conn| {
+        Author {
+            id: 4,
+            name: "Miura Kentaro".into(),
+            ..Default::default()
+        }
+        .create(&mut *conn)
+        .await?;
+        let nested = georm::transaction(&mut *conn, async |conn| {
+            Author {
+                id: 5,
+                name: "Inoue Takehiko".into(),
+                ..Default::default()
+            }
+            .create(&mut *conn)
+            .await?;
+            Author {
+                id: 1,
+                name: "Duplicate".into(),
+                ..Default::default()
+            }
+            .create(&mut *conn)
+            .await
+        })
+        .await;
+        assert!(nested.is_err());
+        Ok::<_, sqlx::Error>(())
+    })
+    .await?;
+    assert!(Author::find(&pool, &4).await?.is_some());
+    assert!(Author::find(&pool, &5).await?.is_none());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn nested_transaction_is_discarded_with_outer_transaction(
+    pool: sqlx::PgPool,
+) -> sqlx::Result<()> {
+    let result = georm::transaction(&pool, async |conn| {
+        georm::transaction(&mut *conn, async |conn| {
+            Author {
+                id: 4,
+                name: "Miura Kentaro".into(),
+                ..Default::default()
+            }
+            .create(&mut *conn)
+            .await
+        })
+        .await?;
+        Author {
+            id: 1,
+            name: "Duplicate".into(),
+            ..Default::default()
+        }
+        .create(&mut *conn)
+        .await
+    })
+    .await;
+    assert!(result.is_err());
+    assert!(Author::find(&pool, &4).await?.is_none());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("simple_struct"))]
+async fn transaction_works_on_existing_transaction(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let mut tx = pool.begin().await?;
+    georm::transaction(&mut tx, async |conn| {
+        Author {
+            id: 4,
+            name: "Miura Kentaro".into(),
+            ..Default::default()
+        }
+        .create(&mut *conn)
+        .await
+    })
+    .await?;
+    tx.rollback().await?;
+    assert!(Author::find(&pool, &4).await?.is_none());
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use georm::{Georm, VersionConflict};
+
+#[derive(Georm, Debug, Clone, PartialEq, Eq)]
+#[georm(table = "wiki_pages", patch)]
+struct WikiPage {
+    #[georm(id)]
+    id: i32,
+    title: String,
+    #[georm(version)]
+    version: i32,
+}
+
+#[sqlx::test(fixtures("versioned"))]
+async fn update_increments_version(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let about = WikiPage {
+        id: 2,
+        title: "About us".into(),
+        version: 3,
+    };
+    let updated = about.clone().update(&pool).await?;
+    assert_eq!(
+        WikiPage {
+            version: 4,
+            ..about
+        },
+        updated
+    );
+    let updated = updated.update(&pool).await?;
+    assert_eq!(5, updated.version);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("versioned"))]
+async fn stale_update_is_a_conflict(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let first_editor = WikiPage::find(&pool, &1).await?.unwrap();
+    let mut second_editor = first_editor.clone();
+    first_editor.update(&pool).await?;
+
+    second_editor.title = "Welcome".into();
+    let error = second_editor.update(&pool).await.unwrap_err();
+    assert!(VersionConflict::matches(&error));
+    let sqlx::Error::Database(error) = error else {
+        panic!("expected a database error");
+    };
+    assert_eq!(Some("wiki_pages"), error.table());
+    assert!(error.try_downcast_ref::<VersionConflict>().is_some());
+    assert_eq!("Home", WikiPage::find(&pool, &1).await?.unwrap().title);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("versioned"))]
+async fn other_errors_are_not_conflicts(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    assert!(!VersionConflict::matches(&sqlx::Error::RowNotFound));
+    let home = WikiPage::find(&pool, &1).await?.unwrap();
+    let error = home.create(&pool).await.unwrap_err();
+    assert!(!VersionConflict::matches(&error));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("versioned"))]
+async fn create_or_update_increments_version(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let about = WikiPage {
+        id: 2,
+        title: "About".into(),
+        version: 1,
+    };
+    let upserted = about.create_or_update(&pool).await?;
+    assert_eq!(4, upserted.version);
+    let contact = WikiPage {
+        id: 3,
+        title: "Contact".into(),
+        version: 1,
+    };
+    let inserted = contact.create_or_update(&pool).await?;
+    assert_eq!(1, inserted.version);
+
+    let home = WikiPage::find(&pool, &1).await?.unwrap();
+    let blog = WikiPage {
+        id: 4,
+        title: "Blog".into(),
+        version: 1,
+    };
+    let upserted = WikiPage::create_or_update_many(&[home.clone(), blog.clone()], &pool).await?;
+    assert_eq!(vec![WikiPage { version: 2, ..home }, blog], upserted);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("versioned"))]
+async fn create_or_update_versioned_checks_version(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let about = WikiPage {
+        id: 2,
+        title: "About us".into(),
+        version: 3,
+    };
+    let updated = about.clone().create_or_update_versioned(&pool).await?;
+    assert_eq!(
+        WikiPage {
+            version: 4,
+            ..about.clone()
+        },
+        updated
+    );
+
+    let stale = WikiPage {
+        title: "Stale".into(),
+        ..about
+    };
+    let error = stale.create_or_update_versioned(&pool).await.unwrap_err();
+    assert!(VersionConflict::matches(&error));
+
+    let contact = WikiPage {
+        id: 3,
+        title: "Contact".into(),
+        version: 1,
+    };
+    let inserted = contact.clone().create_or_update_versioned(&pool).await?;
+    assert_eq!(contact, inserted);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("versioned"))]
+async fn delete_versioned_checks_version(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let about = WikiPage::find(&pool, &2).await?.unwrap();
+    let stale = WikiPage {
+        version: 2,
+        ..about.clone()
+    };
+    let error = stale.delete_versioned(&pool).await.unwrap_err();
+    assert!(VersionConflict::matches(&error));
+    assert!(WikiPage::exists(&pool, &2).await?);
+
+    assert_eq!(1, about.delete_versioned(&pool).await?);
+    assert!(!WikiPage::exists(&pool, &2).await?);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("versioned"))]
+async fn patch_increments_version(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    let patch = WikiPagePatch {
+        title: Some("About us".into()),
+    };
+    let expected = WikiPage {
+        id: 2,
+        title: "About us".into(),
+        version: 4,
+    };
+    assert_eq!(expected, patch.apply(&pool, &2).await?);
+    Ok(())
+}